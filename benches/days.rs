@@ -0,0 +1,24 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+use advent_of_code_2021::inputs::InputSource;
+use advent_of_code_2021::problems::DAYS;
+
+// Times parsing and each part separately for every registered day, using the real inputs
+fn bench_days(c: &mut Criterion) {
+    for day in DAYS.iter() {
+        let problem_raw_input = InputSource::for_day(day.number)
+            .and_then(|input_source| input_source.read())
+            .unwrap_or_else(|err| panic!("couldn't load input for day {}: {}", day.number, err));
+        let parsed_input = day.parse(&problem_raw_input)
+            .unwrap_or_else(|err| panic!("couldn't parse input for day {}: {}", day.number, err));
+
+        let mut group = c.benchmark_group(format!("day{:02}", day.number));
+        group.bench_function("parse", |b| b.iter(|| day.parse(black_box(&problem_raw_input))));
+        group.bench_function("part1", |b| b.iter(|| parsed_input.part1()));
+        group.bench_function("part2", |b| b.iter(|| parsed_input.part2()));
+        group.finish();
+    }
+}
+
+criterion_group!(benches, bench_days);
+criterion_main!(benches);
@@ -0,0 +1,170 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::{parse_token, Error, Result};
+use crate::inputs::InputSource;
+use crate::solution::{Day, Part};
+
+// A small subset of TOML: `[dayN]` tables holding `part1`/`part2` keys with integer or
+// quoted string values, plus `#` comments.
+pub struct Answers {
+    expected: HashMap<(u8, Part), String>,
+}
+
+pub fn answers_path() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("answers.toml")
+}
+
+fn parse_value(value_str: &str) -> Result<String> {
+    if let Some(quoted) = value_str.strip_prefix('"') {
+        return match quoted.strip_suffix('"') {
+            Some(unquoted) if !unquoted.contains('"') => Ok(unquoted.to_string()),
+            _ => Err(Error::parse(value_str, "unterminated string")),
+        };
+    }
+    if value_str.is_empty() || !value_str.chars().all(|c| c.is_ascii_digit() || c == '-') {
+        return Err(Error::parse(value_str, "expected an integer or a quoted string"));
+    }
+    Ok(value_str.to_string())
+}
+
+impl Answers {
+    pub fn parse(raw_answers: &str) -> Result<Answers> {
+        let mut expected = HashMap::new();
+        let mut current_day: Option<u8> = None;
+
+        for raw_line in raw_answers.split('\n') {
+            let line = match raw_line.find('#') {
+                Some(comment_index) => raw_line[..comment_index].trim(),
+                None => raw_line.trim(),
+            };
+            if line.is_empty() {
+                continue;
+            }
+            if let Some(table_name) = line.strip_prefix('[').and_then(|table| table.strip_suffix(']')) {
+                match table_name.trim().strip_prefix("day") {
+                    Some(day_str) => current_day = Some(parse_token::<u8>(day_str)?),
+                    None => return Err(Error::parse(table_name, "expected a table like [day1]")),
+                }
+                continue;
+            }
+
+            let (key, value_str) = match line.split_once('=') {
+                Some((key, value_str)) => (key.trim(), value_str.trim()),
+                None => return Err(Error::parse(line, "expected a key = value pair")),
+            };
+            let part = match key {
+                "part1" => Part::One,
+                "part2" => Part::Two,
+                _ => return Err(Error::parse(key, "expected part1 or part2")),
+            };
+            let day = current_day.ok_or_else(|| Error::parse(key, "answer given outside of a [dayN] table"))?;
+            expected.insert((day, part), parse_value(value_str)?);
+        }
+
+        Ok(Answers { expected })
+    }
+
+    pub fn load(path: &Path) -> Result<Answers> {
+        let raw_answers = fs::read_to_string(path)
+            .map_err(|source| Error::Io { path: path.to_path_buf(), source })?;
+        Answers::parse(&raw_answers).map_err(|err| err.locate(&raw_answers).with_path(path))
+    }
+
+    pub fn expected(&self, day: u8, part: Part) -> Option<&str> {
+        self.expected.get(&(day, part)).map(String::as_str)
+    }
+}
+
+pub enum CheckStatus {
+    Pass,
+    Fail { expected: String },
+    Missing,
+    Error(String),
+}
+
+pub struct CheckResult {
+    pub day: u8,
+    pub part: Part,
+    pub answer: Option<String>,
+    pub status: CheckStatus,
+}
+
+impl CheckResult {
+    pub fn is_failure(&self) -> bool {
+        matches!(self.status, CheckStatus::Fail { .. } | CheckStatus::Error(_))
+    }
+}
+
+fn solve_day(day: &Day) -> Result<Vec<String>> {
+    let input_source = InputSource::for_day(day.number)?;
+    let problem_raw_input = input_source.read()?;
+    let parsed_input = day.parse(&problem_raw_input).map_err(|err| err.with_path(&input_source.name()))?;
+    Ok(Part::ALL.iter().map(|part| parsed_input.solve(*part)).collect())
+}
+
+// Runs every given day against its real input and compares the answers with the manifest
+pub fn check_days(days: &[Day], answers: &Answers) -> Vec<CheckResult> {
+    let mut check_results = Vec::new();
+    for day in days.iter() {
+        match solve_day(day) {
+            Ok(day_answers) => {
+                for (part, answer) in Part::ALL.into_iter().zip(day_answers) {
+                    let status = match answers.expected(day.number, part) {
+                        Some(expected) if expected == answer => CheckStatus::Pass,
+                        Some(expected) => CheckStatus::Fail { expected: expected.to_string() },
+                        None => CheckStatus::Missing,
+                    };
+                    check_results.push(CheckResult { day: day.number, part, answer: Some(answer), status });
+                }
+            },
+            Err(err) => {
+                for part in Part::ALL {
+                    let status = CheckStatus::Error(err.to_string());
+                    check_results.push(CheckResult { day: day.number, part, answer: None, status });
+                }
+            },
+        }
+    }
+    check_results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::problems::DAYS;
+
+    #[test]
+    fn parses_answers() {
+        let answers = Answers::parse("# comment\n[day3]\npart1 = 198\npart2 = \"abc\" # trailing\n").unwrap();
+        assert_eq!(answers.expected(3, Part::One), Some("198"));
+        assert_eq!(answers.expected(3, Part::Two), Some("abc"));
+        assert_eq!(answers.expected(4, Part::One), None);
+    }
+
+    #[test]
+    fn rejects_answers_outside_a_day() {
+        assert!(Answers::parse("part1 = 5").is_err());
+        assert!(Answers::parse("[day1]\npart3 = 5").is_err());
+    }
+
+    #[test]
+    fn real_inputs_match_answers() {
+        let answers = Answers::load(&answers_path()).unwrap();
+        for check_result in check_days(&DAYS, &answers) {
+            // A day fresh from `new-day` has an empty input and nothing to check yet
+            let has_input = InputSource::for_day(check_result.day)
+                .and_then(|input_source| input_source.read())
+                .is_ok_and(|problem_raw_input| !problem_raw_input.is_empty());
+            if !has_input && matches!(check_result.status, CheckStatus::Missing) {
+                continue;
+            }
+            assert!(
+                !check_result.is_failure() && !matches!(check_result.status, CheckStatus::Missing),
+                "day {} part {} did not match answers.toml",
+                check_result.day, check_result.part.number()
+            );
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+#[derive(Debug)]
+pub enum Error {
+    Io { path: PathBuf, source: io::Error },
+    MissingInput { day: u8, searched: Vec<PathBuf> },
+    Parse(ParseError),
+    Scaffold(String),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+#[derive(Debug)]
+pub struct ParseError {
+    pub path: Option<PathBuf>,
+    pub location: Option<Location>,
+    pub text: String,
+    pub message: String,
+    // Address of the offending text. Parsers only ever see slices of the raw input, so this is
+    // enough to work out the line and column once the error gets back to whoever owns the input.
+    text_address: usize,
+}
+
+impl Error {
+    pub fn parse(text: &str, message: impl Into<String>) -> Error {
+        Error::Parse(ParseError {
+            path: None,
+            location: None,
+            text: text.to_string(),
+            message: message.into(),
+            text_address: text.as_ptr() as usize,
+        })
+    }
+
+    pub fn locate(self, raw_input: &str) -> Error {
+        match self {
+            Error::Parse(mut parse_error) if parse_error.location.is_none() => {
+                let input_start = raw_input.as_ptr() as usize;
+                let input_end = input_start + raw_input.len();
+                if (input_start..=input_end).contains(&parse_error.text_address) {
+                    let offset = parse_error.text_address - input_start;
+                    let line_start = raw_input[..offset].rfind('\n').map_or(0, |newline| newline + 1);
+                    parse_error.location = Some(Location {
+                        line: raw_input[..offset].matches('\n').count() + 1,
+                        column: raw_input[line_start..offset].chars().count() + 1,
+                    });
+                }
+                Error::Parse(parse_error)
+            },
+            other => other,
+        }
+    }
+
+    pub fn with_path(self, path: &Path) -> Error {
+        match self {
+            Error::Parse(mut parse_error) => {
+                parse_error.path = Some(path.to_path_buf());
+                Error::Parse(parse_error)
+            },
+            other => other,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io { path, source } => write!(f, "{}: {}", path.display(), source),
+            Error::MissingInput { day, searched } => {
+                write!(f, "no input found for day {}, searched:", day)?;
+                for search_path in searched.iter() {
+                    write!(f, "\n    {}", search_path.display())?;
+                }
+                Ok(())
+            },
+            Error::Parse(parse_error) => {
+                match &parse_error.path {
+                    Some(path) => write!(f, "{}", path.display())?,
+                    None => write!(f, "<input>")?,
+                }
+                if let Some(location) = parse_error.location {
+                    write!(f, ":{}:{}", location.line, location.column)?;
+                }
+                write!(f, ": {}, found {:?}", parse_error.message, parse_error.text)
+            },
+            Error::Scaffold(message) => write!(f, "can't add day: {}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Io { source, .. } => Some(source),
+            Error::MissingInput { .. } | Error::Parse(_) | Error::Scaffold(_) => None,
+        }
+    }
+}
+
+pub fn parse_token<T: FromStr>(token: &str) -> Result<T> where T::Err: fmt::Display {
+    token.parse::<T>().map_err(|err| Error::parse(token, err.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn locates_offending_text() {
+        let raw_input = "12\n34\n5x6";
+        let bad_token = &raw_input[7..8];
+        match Error::parse(bad_token, "expected a digit").locate(raw_input) {
+            Error::Parse(parse_error) => {
+                assert_eq!(parse_error.location, Some(Location { line: 3, column: 2 }));
+                assert_eq!(parse_error.text, "x");
+            },
+            other => panic!("expected a parse error, got {}", other),
+        }
+    }
+
+    #[test]
+    fn displays_path_and_location() {
+        let raw_input = "forward 5\nsideways 3";
+        let err = Error::parse(&raw_input[10..18], "bad instruction")
+            .locate(raw_input)
+            .with_path(Path::new("i2.txt"));
+        assert_eq!(err.to_string(), "i2.txt:2:1: bad instruction, found \"sideways\"");
+    }
+}
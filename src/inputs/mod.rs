@@ -1,8 +1,154 @@
-pub mod i1;
+use std::env;
 use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
 
-pub fn read_input(filename: &str) -> String {
-    let contents = fs::read_to_string(filename)
-        .expect("Something went wrong reading the file");
-    contents.trim_end().to_string()
+use crate::error::{Error, Result};
+
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+
+// Copies of the puzzle inputs baked into the binary, used when the .txt files aren't around
+const EMBEDDED_INPUTS: [(u8, &str); 12] = [
+    (1, include_str!("i1.txt")),
+    (2, include_str!("i2.txt")),
+    (3, include_str!("i3.txt")),
+    (4, include_str!("i4.txt")),
+    (5, include_str!("i5.txt")),
+    (6, include_str!("i6.txt")),
+    (7, include_str!("i7.txt")),
+    (8, include_str!("i8.txt")),
+    (9, include_str!("i9.txt")),
+    (10, include_str!("i10.txt")),
+    (11, include_str!("i11.txt")),
+    (12, include_str!("i12.txt")),
+];
+
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+    Embedded { day: u8, contents: &'static str },
+}
+
+impl InputSource {
+    pub fn embedded(day: u8) -> Option<InputSource> {
+        let (_, contents) = EMBEDDED_INPUTS.iter().find(|(embedded_day, _)| *embedded_day == day)?;
+        Some(InputSource::Embedded { day, contents })
+    }
+
+    // The day's input file, otherwise the copy embedded at build time. Pointing AOC_INPUT_DIR
+    // somewhere means those inputs are wanted, so only that directory is searched then.
+    pub fn for_day(day: u8) -> Result<InputSource> {
+        InputSource::find(env::var_os(INPUT_DIR_ENV).as_deref().map(Path::new), day)
+    }
+
+    fn find(input_dir: Option<&Path>, day: u8) -> Result<InputSource> {
+        let searched = search_paths(input_dir, day);
+        if let Some(input_path) = searched.iter().find(|input_path| input_path.is_file()) {
+            return Ok(InputSource::File(input_path.clone()));
+        }
+        match InputSource::embedded(day) {
+            Some(embedded_source) if input_dir.is_none() => Ok(embedded_source),
+            _ => Err(Error::MissingInput { day, searched }),
+        }
+    }
+
+    pub fn name(&self) -> PathBuf {
+        match self {
+            InputSource::File(path) => path.clone(),
+            InputSource::Stdin => PathBuf::from("<stdin>"),
+            InputSource::Embedded { day, .. } => PathBuf::from(format!("<embedded i{}.txt>", day)),
+        }
+    }
+
+    pub fn read(&self) -> Result<String> {
+        let contents = match self {
+            InputSource::File(path) => fs::read_to_string(path),
+            InputSource::Stdin => {
+                let mut contents = String::new();
+                io::stdin().read_to_string(&mut contents).map(|_| contents)
+            },
+            InputSource::Embedded { contents, .. } => Ok(contents.to_string()),
+        };
+        match contents {
+            Ok(contents) => Ok(contents.trim_end().to_string()),
+            Err(source) => Err(Error::Io { path: self.name(), source }),
+        }
+    }
+}
+
+impl From<&str> for InputSource {
+    fn from(input_arg: &str) -> Self {
+        match input_arg {
+            "-" => InputSource::Stdin,
+            input_path => InputSource::File(PathBuf::from(input_path)),
+        }
+    }
+}
+
+pub fn read_input(filename: &str) -> Result<String> {
+    InputSource::File(Path::new(filename).to_path_buf()).read()
+}
+
+pub fn day_input_file_name(day: u8) -> String {
+    format!("i{}.txt", day)
+}
+
+// Where the crate keeps its inputs, independent of the directory the binary is run from
+pub fn day_input_path(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("src").join("inputs").join(day_input_file_name(day))
+}
+
+fn search_paths(input_dir: Option<&Path>, day: u8) -> Vec<PathBuf> {
+    match input_dir {
+        Some(input_dir) => vec![input_dir.join(day_input_file_name(day))],
+        None => vec![day_input_path(day)],
+    }
+}
+
+pub fn input_search_paths(day: u8) -> Vec<PathBuf> {
+    search_paths(env::var_os(INPUT_DIR_ENV).as_deref().map(Path::new), day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dash_reads_stdin() {
+        assert!(matches!(InputSource::from("-"), InputSource::Stdin));
+        assert!(matches!(InputSource::from("my/i4.txt"), InputSource::File(path) if path == Path::new("my/i4.txt")));
+        assert_eq!(InputSource::Stdin.name(), PathBuf::from("<stdin>"));
+    }
+
+    #[test]
+    fn embeds_every_day() {
+        let embedded_source = InputSource::embedded(1).unwrap();
+        assert_eq!(embedded_source.name(), PathBuf::from("<embedded i1.txt>"));
+        assert_eq!(embedded_source.read().unwrap(), include_str!("i1.txt").trim_end());
+        assert!(InputSource::embedded(0).is_none());
+    }
+
+    #[test]
+    fn searches_only_the_input_dir_when_set() {
+        assert_eq!(search_paths(None, 4), vec![day_input_path(4)]);
+        let input_dir = Path::new("/nonexistent/aoc-inputs");
+        assert_eq!(search_paths(Some(input_dir), 4), vec![input_dir.join("i4.txt")]);
+
+        // The crate's own input must not stand in for one missing from the chosen directory
+        let err = InputSource::find(Some(input_dir), 4).map(|_| ()).unwrap_err();
+        assert_eq!(err.to_string(), "no input found for day 4, searched:\n    /nonexistent/aoc-inputs/i4.txt");
+    }
+
+    #[test]
+    fn finds_input_in_the_input_dir() {
+        let input_dir = env::temp_dir().join(format!("aoc-inputs-{}", std::process::id()));
+        fs::create_dir_all(&input_dir).unwrap();
+        fs::write(input_dir.join("i2.txt"), "forward 5\n").unwrap();
+        let input_source = InputSource::find(Some(&input_dir), 2).unwrap();
+        assert_eq!(input_source.name(), input_dir.join("i2.txt"));
+        assert_eq!(input_source.read().unwrap(), "forward 5");
+        fs::remove_dir_all(&input_dir).unwrap();
+
+        assert!(matches!(InputSource::find(None, 2).unwrap(), InputSource::File(path) if path == day_input_path(2)));
+    }
 }
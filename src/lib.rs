@@ -1,2 +1,13 @@
+pub mod answers;
+pub mod error;
+pub mod heap;
 pub mod inputs;
+pub mod logging;
+pub mod problems;
+pub mod report;
+pub mod scaffold;
 pub mod shared;
+pub mod solution;
+
+pub use error::{Error, Result};
+pub use solution::{Day, Part, Solution};
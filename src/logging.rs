@@ -0,0 +1,49 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+
+// Diagnostics from the solutions go to stderr, and only when the runner asks for them, so
+// stdout carries nothing but answers.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Verbosity {
+    Quiet = 0,
+    Debug = 1,
+    Trace = 2,
+}
+
+static VERBOSITY: AtomicU8 = AtomicU8::new(Verbosity::Quiet as u8);
+
+impl Verbosity {
+    // Maps a count of -v flags onto a level, saturating at Trace
+    pub fn from_count(verbose_count: usize) -> Verbosity {
+        match verbose_count {
+            0 => Verbosity::Quiet,
+            1 => Verbosity::Debug,
+            _ => Verbosity::Trace,
+        }
+    }
+}
+
+pub fn set_verbosity(verbosity: Verbosity) {
+    VERBOSITY.store(verbosity as u8, Ordering::Relaxed);
+}
+
+pub fn enabled(verbosity: Verbosity) -> bool {
+    VERBOSITY.load(Ordering::Relaxed) >= verbosity as u8
+}
+
+#[macro_export]
+macro_rules! debug_log {
+    ($($arg:tt)*) => {
+        if $crate::logging::enabled($crate::logging::Verbosity::Debug) {
+            eprintln!($($arg)*);
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! trace_log {
+    ($($arg:tt)*) => {
+        if $crate::logging::enabled($crate::logging::Verbosity::Trace) {
+            eprintln!($($arg)*);
+        }
+    };
+}
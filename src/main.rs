@@ -1,3 +1,289 @@
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process;
+
+use advent_of_code_2021::answers::{answers_path, check_days, Answers, CheckStatus};
+use advent_of_code_2021::heap::CountingAllocator;
+use advent_of_code_2021::inputs::InputSource;
+use advent_of_code_2021::logging::{set_verbosity, Verbosity};
+use advent_of_code_2021::problems::p1::{DepthStatistics, SonarSweep};
+use advent_of_code_2021::problems::p2::{AimModel, Dive, Position};
+use advent_of_code_2021::problems::{get_day, DAYS};
+use advent_of_code_2021::report::{measure_day, render_json, render_json_error, render_table, PartReport};
+use advent_of_code_2021::scaffold::scaffold_day;
+use advent_of_code_2021::{Day, Error, Part, Solution};
+
+#[global_allocator]
+static GLOBAL_ALLOCATOR: CountingAllocator = CountingAllocator;
+
+const USAGE: &str = "usage: advent-of-code-2021 <day>|all [--part 1|2|both] [--input <path>|-] [--embedded]
+                                 [--format plain|table|json] [--report] [-v|-vv]
+       advent-of-code-2021 check [--answers <path>]
+       advent-of-code-2021 new-day <day>
+       advent-of-code-2021 sonar-stats [--input <path>|-]
+       advent-of-code-2021 dive-trace <csv path> [--input <path>|-]
+
+Inputs are read from $AOC_INPUT_DIR when it is set, and otherwise from the crate's src/inputs
+directory or the copies built into the binary.
+--format table (or --report) prints answers, parse and solve times and peak heap use per day and
+part; --format json prints the same as one JSON object per line. -v and -vv log solution
+diagnostics to stderr. sonar-stats summarizes the day 1 depths, to sanity-check a sonar input.
+dive-trace writes every position along the day 2 part 2 course to a CSV file.";
+
+enum Command {
+    Solve(RunnerArgs),
+    Check { answers_path: Option<String> },
+    NewDay { day: u8 },
+    SonarStats { input_path: Option<String> },
+    DiveTrace { csv_path: String, input_path: Option<String> },
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum OutputFormat {
+    Plain,
+    Table,
+    Json,
+}
+
+struct RunnerArgs {
+    days: Vec<u8>,
+    parts: Vec<Part>,
+    input_path: Option<String>,
+    use_embedded: bool,
+    output_format: OutputFormat,
+}
+
+fn parse_parts(part_str: &str) -> Result<Vec<Part>, String> {
+    match part_str {
+        "1" => Ok(vec![Part::One]),
+        "2" => Ok(vec![Part::Two]),
+        "both" => Ok(vec![Part::One, Part::Two]),
+        bad_part => Err(format!("bad part selector {}", bad_part)),
+    }
+}
+
+fn parse_output_format(format_str: &str) -> Result<OutputFormat, String> {
+    match format_str {
+        "plain" => Ok(OutputFormat::Plain),
+        "table" => Ok(OutputFormat::Table),
+        "json" => Ok(OutputFormat::Json),
+        bad_format => Err(format!("bad output format {}", bad_format)),
+    }
+}
+
+fn parse_check_args(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut answers_path: Option<String> = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--answers" => answers_path = Some(args.next().ok_or("--answers needs a value")?),
+            extra_arg => return Err(format!("unexpected argument {}", extra_arg)),
+        }
+    }
+    Ok(Command::Check { answers_path })
+}
+
+fn parse_new_day_args(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let day_str = args.next().ok_or("new-day needs a day number")?;
+    let day = day_str.parse::<u8>().map_err(|_| format!("bad day number {}", day_str))?;
+    match args.next() {
+        Some(extra_arg) => Err(format!("unexpected argument {}", extra_arg)),
+        None => Ok(Command::NewDay { day }),
+    }
+}
+
+fn parse_sonar_stats_args(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut input_path: Option<String> = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-i" | "--input" => input_path = Some(args.next().ok_or("--input needs a value")?),
+            extra_arg => return Err(format!("unexpected argument {}", extra_arg)),
+        }
+    }
+    Ok(Command::SonarStats { input_path })
+}
+
+fn parse_dive_trace_args(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let csv_path = args.next().ok_or("dive-trace needs a CSV path")?;
+    let mut input_path: Option<String> = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-i" | "--input" => input_path = Some(args.next().ok_or("--input needs a value")?),
+            extra_arg => return Err(format!("unexpected argument {}", extra_arg)),
+        }
+    }
+    Ok(Command::DiveTrace { csv_path, input_path })
+}
+
+fn parse_days(days_str: &str) -> Result<Vec<u8>, String> {
+    match days_str {
+        "all" => Ok(DAYS.iter().map(|day| day.number).collect()),
+        day_str => Ok(vec![day_str.parse::<u8>().map_err(|_| format!("bad day number {}", day_str))?]),
+    }
+}
+
+fn parse_args(mut args: impl Iterator<Item = String>) -> Result<Command, String> {
+    let mut days: Option<Vec<u8>> = None;
+    let mut parts = vec![Part::One, Part::Two];
+    let mut input_path: Option<String> = None;
+    let mut use_embedded = false;
+    let mut output_format = OutputFormat::Plain;
+    let mut verbose_count = 0;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "check" if days.is_none() => return parse_check_args(args),
+            "new-day" if days.is_none() => return parse_new_day_args(args),
+            "sonar-stats" if days.is_none() => return parse_sonar_stats_args(args),
+            "dive-trace" if days.is_none() => return parse_dive_trace_args(args),
+            "-p" | "--part" => {
+                let part_str = args.next().ok_or("--part needs a value")?;
+                parts = parse_parts(&part_str)?;
+            },
+            "-i" | "--input" => {
+                input_path = Some(args.next().ok_or("--input needs a value")?);
+            },
+            "--embedded" => use_embedded = true,
+            "-f" | "--format" => {
+                let format_str = args.next().ok_or("--format needs a value")?;
+                output_format = parse_output_format(&format_str)?;
+            },
+            "--report" => output_format = OutputFormat::Table,
+            "-v" | "--verbose" => verbose_count += 1,
+            "-vv" => verbose_count += 2,
+            days_str if days.is_none() => days = Some(parse_days(days_str)?),
+            extra_arg => return Err(format!("unexpected argument {}", extra_arg)),
+        }
+    }
+
+    set_verbosity(Verbosity::from_count(verbose_count));
+    match days {
+        Some(days) if days.len() > 1 && input_path.is_some() => Err(String::from("--input needs a single day")),
+        Some(days) => Ok(Command::Solve(RunnerArgs { days, parts, input_path, use_embedded, output_format })),
+        None => Err(String::from("missing day number")),
+    }
+}
+
+fn run(runner_args: &RunnerArgs, day: &Day) -> Result<Vec<PartReport>, Error> {
+    let input_source = match (&runner_args.input_path, runner_args.use_embedded) {
+        (Some(input_path), _) => InputSource::from(input_path.as_str()),
+        (None, true) => InputSource::embedded(day.number).ok_or(Error::MissingInput { day: day.number, searched: Vec::new() })?,
+        (None, false) => InputSource::for_day(day.number)?,
+    };
+    let problem_raw_input = input_source.read()?;
+    measure_day(day, &problem_raw_input, &runner_args.parts).map_err(|err| err.with_path(&input_source.name()))
+}
+
+// In JSON mode a day that fails still gets one error object per part and the other days carry
+// on, so the return value says whether every day succeeded
+fn run_days(runner_args: &RunnerArgs) -> Result<bool, Error> {
+    let mut table_reports = Vec::new();
+    let mut all_succeeded = true;
+    for day_number in runner_args.days.iter() {
+        let day = get_day(*day_number).unwrap_or_else(|| {
+            eprintln!("day {} is not implemented", day_number);
+            process::exit(1);
+        });
+        match (run(runner_args, day), runner_args.output_format) {
+            (Ok(part_reports), OutputFormat::Plain) => {
+                for part_report in part_reports.iter() {
+                    println!("{}", part_report.answer);
+                }
+            },
+            (Ok(part_reports), OutputFormat::Table) => table_reports.extend(part_reports),
+            (Ok(part_reports), OutputFormat::Json) => {
+                for part_report in part_reports.iter() {
+                    println!("{}", render_json(part_report));
+                }
+            },
+            (Err(err), OutputFormat::Json) => {
+                all_succeeded = false;
+                for part in runner_args.parts.iter() {
+                    println!("{}", render_json_error(day.number, *part, &err));
+                }
+            },
+            (Err(err), _) => return Err(err),
+        }
+    }
+    if runner_args.output_format == OutputFormat::Table {
+        print!("{}", render_table(&table_reports));
+    }
+    Ok(all_succeeded)
+}
+
+// Prints one line per day and part, returning whether everything that had an answer matched
+fn check(answers_arg: &Option<String>) -> Result<bool, Error> {
+    let answers = Answers::load(&answers_arg.as_ref().map_or_else(answers_path, PathBuf::from))?;
+    let check_results = check_days(&DAYS, &answers);
+    for check_result in check_results.iter() {
+        let answer = check_result.answer.as_deref().unwrap_or("-");
+        let status = match &check_result.status {
+            CheckStatus::Pass => String::from("pass"),
+            CheckStatus::Fail { expected } => format!("FAIL     expected {}", expected),
+            CheckStatus::Missing => String::from("missing"),
+            CheckStatus::Error(err) => format!("ERROR    {}", err),
+        };
+        println!("day {:>2} part {}  {:<16} {}", check_result.day, check_result.part.number(), answer, status);
+    }
+    Ok(!check_results.iter().any(|check_result| check_result.is_failure()))
+}
+
+fn sonar_stats(input_path: &Option<String>) -> Result<bool, Error> {
+    let input_source = match input_path {
+        Some(input_path) => InputSource::from(input_path.as_str()),
+        None => InputSource::for_day(SonarSweep::DAY)?,
+    };
+    let problem_raw_input = input_source.read()?;
+    let depths = SonarSweep::parse(&problem_raw_input)
+        .map_err(|err| err.locate(&problem_raw_input).with_path(&input_source.name()))?;
+    match DepthStatistics::from_depths(&depths) {
+        Some(depth_statistics) => println!("{}", depth_statistics),
+        None => println!("no depths"),
+    }
+    Ok(true)
+}
+
+fn dive_trace(csv_path: &str, input_path: &Option<String>) -> Result<bool, Error> {
+    let input_source = match input_path {
+        Some(input_path) => InputSource::from(input_path.as_str()),
+        None => InputSource::for_day(Dive::DAY)?,
+    };
+    let problem_raw_input = input_source.read()?;
+    let instructions = Dive::parse(&problem_raw_input)
+        .map_err(|err| err.locate(&problem_raw_input).with_path(&input_source.name()))?;
+    let trajectory = Position::default().run_traced(&instructions, &AimModel);
+    trajectory.save_csv(Path::new(csv_path))?;
+    println!("wrote {} positions to {}", trajectory.positions.len(), csv_path);
+    Ok(true)
+}
+
+// Scaffolds into this crate's own source tree, so it's meant to be run through cargo
+fn new_day(day: u8) -> Result<bool, Error> {
+    for touched_path in scaffold_day(Path::new(env!("CARGO_MANIFEST_DIR")), day)? {
+        println!("wrote {}", touched_path.display());
+    }
+    println!("rename Day{0} in src/problems/p{0}.rs, then fill in the example and its expected answers", day);
+    Ok(true)
+}
+
 fn main() {
-    println!("Hello, world!");
+    let command = parse_args(env::args().skip(1)).unwrap_or_else(|err| {
+        eprintln!("{}\n{}", err, USAGE);
+        process::exit(2);
+    });
+
+    let outcome = match command {
+        Command::Solve(runner_args) => run_days(&runner_args),
+        Command::Check { answers_path } => check(&answers_path),
+        Command::NewDay { day } => new_day(day),
+        Command::SonarStats { input_path } => sonar_stats(&input_path),
+        Command::DiveTrace { csv_path, input_path } => dive_trace(&csv_path, &input_path),
+    };
+    match outcome {
+        Ok(true) => (),
+        Ok(false) => process::exit(1),
+        Err(err) => {
+            eprintln!("error: {}", err);
+            process::exit(1);
+        },
+    }
 }
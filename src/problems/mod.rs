@@ -0,0 +1,33 @@
+use crate::solution::Day;
+
+pub mod p1;
+pub mod p2;
+pub mod p3;
+pub mod p4;
+pub mod p5;
+pub mod p6;
+pub mod p7;
+pub mod p8;
+pub mod p9;
+pub mod p10;
+pub mod p11;
+pub mod p12;
+
+pub static DAYS: [Day; 12] = [
+    Day::new::<p1::SonarSweep>(),
+    Day::new::<p2::Dive>(),
+    Day::new::<p3::BinaryDiagnostic>(),
+    Day::new::<p4::GiantSquid>(),
+    Day::new::<p5::HydrothermalVenture>(),
+    Day::new::<p6::Lanternfish>(),
+    Day::new::<p7::TreacheryOfWhales>(),
+    Day::new::<p8::SevenSegmentSearch>(),
+    Day::new::<p9::SmokeBasin>(),
+    Day::new::<p10::SyntaxScoring>(),
+    Day::new::<p11::DumboOctopus>(),
+    Day::new::<p12::PassagePathing>(),
+];
+
+pub fn get_day(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
@@ -0,0 +1,229 @@
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt;
+
+use crate::error::Result;
+use crate::shared::parse::parse_lines;
+use crate::solution::Solution;
+
+fn get_depths_list_u32(file_contents: &str) -> Result<Vec<u32>> {
+    parse_lines::<u32>(file_contents)
+}
+
+// Counts how often the sum of a sliding window of depths grows, one depth at a time. Consecutive
+// windows share all but one depth, so the newer window is larger exactly when the depth entering
+// it is larger than the one that just left. Only the last `window_size` depths are kept.
+pub struct SonarAnalyzer {
+    window_size: usize,
+    recent_depths: VecDeque<u32>,
+    increases: u32,
+}
+
+impl SonarAnalyzer {
+    pub fn new(window_size: usize) -> SonarAnalyzer {
+        if window_size == 0 {
+            panic!("a sonar window needs at least one depth");
+        }
+        SonarAnalyzer { window_size, recent_depths: VecDeque::with_capacity(window_size + 1), increases: 0 }
+    }
+
+    pub fn push(&mut self, depth: u32) {
+        self.recent_depths.push_back(depth);
+        if self.recent_depths.len() > self.window_size {
+            let leaving_depth = self.recent_depths.pop_front().unwrap();
+            if depth > leaving_depth {
+                self.increases += 1;
+            }
+        }
+    }
+
+    pub fn increases(&self) -> u32 {
+        self.increases
+    }
+}
+
+impl Extend<u32> for SonarAnalyzer {
+    fn extend<I: IntoIterator<Item = u32>>(&mut self, depths: I) {
+        for depth in depths {
+            self.push(depth);
+        }
+    }
+}
+
+// Window 1 compares single depths (part 1); the puzzle's part 2 uses windows of 3
+pub fn count_window_increases(depths: impl IntoIterator<Item = u32>, window_size: usize) -> u32 {
+    let mut sonar_analyzer = SonarAnalyzer::new(window_size);
+    sonar_analyzer.extend(depths);
+    sonar_analyzer.increases()
+}
+
+// Characters from shallowest to deepest for the sparkline
+const SPARKLINE_LEVELS: &[u8] = b"_.-~=+*#%@";
+const SPARKLINE_WIDTH: usize = 60;
+const HISTOGRAM_BUCKET_WIDTH: i64 = 10;
+
+// A summary of the sweep for sanity-checking a sonar input before trusting the increase counts
+pub struct DepthStatistics {
+    pub min_depth: u32,
+    pub max_depth: u32,
+    pub mean_depth: f64,
+    // Longest runs of strictly increasing or decreasing depths, counted in depths
+    pub longest_increasing_run: usize,
+    pub longest_decreasing_run: usize,
+    // The change between neighbouring depths with the biggest magnitude
+    pub largest_jump: i64,
+    // Number of deltas falling in each bucket, keyed by the bucket's lowest delta
+    pub delta_histogram: BTreeMap<i64, usize>,
+    pub sparkline: String,
+}
+
+impl DepthStatistics {
+    // None for an empty sweep, which has no meaningful min, max or mean
+    pub fn from_depths(depths: &[u32]) -> Option<DepthStatistics> {
+        let min_depth = *depths.iter().min()?;
+        let max_depth = *depths.iter().max()?;
+        let mean_depth = depths.iter().map(|depth| *depth as f64).sum::<f64>() / depths.len() as f64;
+
+        let (mut longest_increasing_run, mut longest_decreasing_run) = (1, 1);
+        let (mut increasing_run, mut decreasing_run) = (1, 1);
+        let mut largest_jump = 0i64;
+        let mut delta_histogram = BTreeMap::new();
+        for depth_pair in depths.windows(2) {
+            let delta = depth_pair[1] as i64 - depth_pair[0] as i64;
+            increasing_run = if delta > 0 { increasing_run + 1 } else { 1 };
+            decreasing_run = if delta < 0 { decreasing_run + 1 } else { 1 };
+            longest_increasing_run = longest_increasing_run.max(increasing_run);
+            longest_decreasing_run = longest_decreasing_run.max(decreasing_run);
+            if delta.abs() > largest_jump.abs() {
+                largest_jump = delta;
+            }
+            let bucket_start = delta.div_euclid(HISTOGRAM_BUCKET_WIDTH) * HISTOGRAM_BUCKET_WIDTH;
+            *delta_histogram.entry(bucket_start).or_insert(0) += 1;
+        }
+
+        Some(DepthStatistics {
+            min_depth,
+            max_depth,
+            mean_depth,
+            longest_increasing_run,
+            longest_decreasing_run,
+            largest_jump,
+            delta_histogram,
+            sparkline: get_sparkline(depths, min_depth, max_depth, SPARKLINE_WIDTH),
+        })
+    }
+}
+
+// Squeezes the sweep into at most `width` columns, each showing the mean depth of its stretch
+fn get_sparkline(depths: &[u32], min_depth: u32, max_depth: u32, width: usize) -> String {
+    let depth_range = (max_depth - min_depth).max(1) as f64;
+    let stretch_len = depths.len().div_ceil(width).max(1);
+    depths.chunks(stretch_len)
+        .map(|stretch| {
+            let stretch_mean = stretch.iter().map(|depth| *depth as f64).sum::<f64>() / stretch.len() as f64;
+            let level = (stretch_mean - min_depth as f64) / depth_range * (SPARKLINE_LEVELS.len() - 1) as f64;
+            SPARKLINE_LEVELS[level.round() as usize] as char
+        })
+        .collect()
+}
+
+impl fmt::Display for DepthStatistics {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "depths: min {}, max {}, mean {:.1}", self.min_depth, self.max_depth, self.mean_depth)?;
+        writeln!(f, "longest runs: {} increasing, {} decreasing", self.longest_increasing_run, self.longest_decreasing_run)?;
+        writeln!(f, "largest jump: {:+}", self.largest_jump)?;
+        let largest_bucket = self.delta_histogram.values().copied().max().unwrap_or(1);
+        for (bucket_start, delta_count) in self.delta_histogram.iter() {
+            let bar_len = (delta_count * 40).div_ceil(largest_bucket);
+            writeln!(
+                f, "{:>6}..{:<6} {:>6} {}",
+                bucket_start, bucket_start + HISTOGRAM_BUCKET_WIDTH, delta_count, "#".repeat(bar_len)
+            )?;
+        }
+        write!(f, "profile: {}", self.sparkline)
+    }
+}
+
+const SLIDER_WINDOW_SIZE: usize = 3;
+
+pub struct SonarSweep;
+
+impl Solution for SonarSweep {
+    const DAY: u8 = 1;
+
+    type Input = Vec<u32>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(raw_input: &str) -> Result<Vec<u32>> {
+        get_depths_list_u32(raw_input)
+    }
+
+    fn part1(depths_list_u32: &Vec<u32>) -> u32 {
+        count_window_increases(depths_list_u32.iter().copied(), 1)
+    }
+
+    fn part2(depths_list_u32: &Vec<u32>) -> u32 {
+        count_window_increases(depths_list_u32.iter().copied(), SLIDER_WINDOW_SIZE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../inputs/i1_example.txt");
+
+    #[test]
+    fn solves_example_part1() {
+        let input = SonarSweep::parse(EXAMPLE.trim_end()).unwrap();
+        assert_eq!(SonarSweep::part1(&input), 7);
+    }
+
+    #[test]
+    fn solves_example_part2() {
+        let input = SonarSweep::parse(EXAMPLE.trim_end()).unwrap();
+        assert_eq!(SonarSweep::part2(&input), 5);
+    }
+
+    #[test]
+    fn counts_any_window_size() {
+        let depths = SonarSweep::parse(EXAMPLE.trim_end()).unwrap();
+        assert_eq!(count_window_increases(depths.iter().copied(), 1), 7);
+        assert_eq!(count_window_increases(depths.iter().copied(), 3), 5);
+        assert_eq!(count_window_increases(depths.iter().copied(), 10), 0);
+        // A window as long as the whole sweep never slides
+        assert_eq!(count_window_increases(depths.iter().copied(), depths.len()), 0);
+    }
+
+    #[test]
+    fn streams_without_holding_the_sweep() {
+        // A zig-zag that climbs by one every two samples: 0, 2, 1, 3, 2, 4, ...
+        let zig_zag = (0..1_000_000u32).map(|sample_index| sample_index / 2 + (sample_index % 2) * 2);
+        assert_eq!(count_window_increases(zig_zag.clone(), 1), 500_000);
+        assert_eq!(count_window_increases(zig_zag, 2), 999_998);
+    }
+
+    #[test]
+    fn summarizes_depths() {
+        let depths = SonarSweep::parse(EXAMPLE.trim_end()).unwrap();
+        let depth_statistics = DepthStatistics::from_depths(&depths).unwrap();
+        assert_eq!((depth_statistics.min_depth, depth_statistics.max_depth), (199, 269));
+        assert!((depth_statistics.mean_depth - 225.6).abs() < 1e-9);
+        // 199 to 210 and 200 to 269 both climb four depths; no drop lasts longer than one step
+        assert_eq!(depth_statistics.longest_increasing_run, 4);
+        assert_eq!(depth_statistics.longest_decreasing_run, 2);
+        assert_eq!(depth_statistics.largest_jump, 33);
+        assert_eq!(depth_statistics.delta_histogram, BTreeMap::from([(-10, 2), (0, 5), (20, 1), (30, 1)]));
+        assert_eq!(depth_statistics.sparkline, "__.._.+@%%");
+        assert!(DepthStatistics::from_depths(&[]).is_none());
+    }
+
+    #[test]
+    fn squeezes_long_sweeps_into_the_sparkline() {
+        let depths: Vec<u32> = (0..1000).collect();
+        let sparkline = get_sparkline(&depths, 0, 999, 10);
+        assert_eq!(sparkline.len(), 10);
+        assert!(sparkline.starts_with('_') && sparkline.ends_with('@'));
+        assert_eq!(get_sparkline(&[5, 5], 5, 5, 10), "__");
+    }
+}
@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+
+use crate::error::{Error, Result};
+use crate::shared::parse::{lines, map_lines};
+use crate::solution::Solution;
+
+const NEST_TUPLES: [(char, char); 4] = [
+    ('(', ')'), ('[', ']'), ('{', '}'), ('<', '>')
+];
+
+// Every closing bracket multiplies the score by 5, so only this many fit in a u64
+const MAX_COMPLETION_LEN: usize = 27;
+
+pub enum SyntaxError {
+    Corrupted(char),
+    Incomplete(String)
+}
+
+// Every line has to be corrupted or incomplete, since those are the only two the scoring knows
+fn evaluate_line(line_str: &str, nest_map: &HashMap<char, char>) -> Result<SyntaxError> {
+    let mut char_stack: Vec<char> = Vec::new();
+
+    for (char_index, next_char) in line_str.char_indices() {
+        if nest_map.contains_key(&next_char) {
+            char_stack.push(next_char);
+            continue;
+        }
+        if !nest_map.values().any(|unnest_char| *unnest_char == next_char) {
+            return Err(Error::parse(&line_str[char_index..], "expected only brackets"));
+        }
+        match char_stack.pop() {
+            Some(last_nest_char) if nest_map[&last_nest_char] != next_char => {
+                return Ok(SyntaxError::Corrupted(next_char));
+            },
+            Some(_) => (),
+            None => return Err(Error::parse(&line_str[char_index..], "closing bracket with nothing open")),
+        }
+    }
+
+    if char_stack.is_empty() {
+        return Err(Error::parse(line_str, "expected a corrupted or incomplete line"));
+    }
+    if char_stack.len() > MAX_COMPLETION_LEN {
+        return Err(Error::parse(line_str, format!("completion is longer than the {} brackets a score can hold", MAX_COMPLETION_LEN)));
+    }
+    let completing_string = char_stack.iter().rev().map(|curr_incomplete_char| nest_map[curr_incomplete_char]).collect();
+    Ok(SyntaxError::Incomplete(completing_string))
+}
+
+fn get_corrupt_score(corrupt_char: char) -> u32 {
+    match corrupt_char {
+        ')' => 3,
+        ']' => 57,
+        '}' => 1197,
+        '>' => 25137,
+        _ => panic!("bad char {}", corrupt_char)
+    }
+}
+
+fn get_incomplete_score(completing_string: &str) -> u64 {
+    completing_string.chars().fold(0, |sum, completing_char| {
+        (sum * 5) + match completing_char {
+            ')' => 1,
+            ']' => 2,
+            '}' => 3,
+            '>' => 4,
+            _ => panic!("bad char {}", completing_char)
+        }
+    })
+}
+
+fn get_corrupt_total(syntax_errors: &[SyntaxError]) -> u32 {
+    syntax_errors.iter().map(|syntax_error| match syntax_error {
+        SyntaxError::Corrupted(bad_char) => get_corrupt_score(*bad_char),
+        SyntaxError::Incomplete(_) => 0,
+    }).sum()
+}
+
+// Parsing guarantees at least one incomplete line, so there is always a middle score
+fn get_middle_incomplete_score(syntax_errors: &[SyntaxError]) -> u64 {
+    let mut incomplete_scores: Vec<u64> = syntax_errors.iter().filter_map(|syntax_error| match syntax_error {
+        SyntaxError::Incomplete(completing_string) => Some(get_incomplete_score(completing_string)),
+        SyntaxError::Corrupted(_) => None,
+    }).collect();
+    incomplete_scores.sort_unstable();
+    incomplete_scores[incomplete_scores.len() / 2]
+}
+
+fn get_bad_lines(file_contents: &str) -> Result<Vec<SyntaxError>> {
+    let nest_map = HashMap::from(NEST_TUPLES);
+    let syntax_errors = map_lines(file_contents, |line_str| evaluate_line(line_str, &nest_map))?;
+    if !syntax_errors.iter().any(|syntax_error| matches!(syntax_error, SyntaxError::Incomplete(_))) {
+        return Err(Error::parse(lines(file_contents).next().unwrap(), "expected at least one incomplete line"));
+    }
+    Ok(syntax_errors)
+}
+
+pub struct SyntaxScoring;
+
+impl Solution for SyntaxScoring {
+    const DAY: u8 = 10;
+
+    type Input = Vec<SyntaxError>;
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    fn parse(raw_input: &str) -> Result<Vec<SyntaxError>> {
+        get_bad_lines(raw_input)
+    }
+
+    fn part1(syntax_errors: &Vec<SyntaxError>) -> u32 {
+        get_corrupt_total(syntax_errors)
+    }
+
+    fn part2(syntax_errors: &Vec<SyntaxError>) -> u64 {
+        get_middle_incomplete_score(syntax_errors)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../inputs/i10_example.txt");
+
+    #[test]
+    fn solves_example_part1() {
+        let input = SyntaxScoring::parse(EXAMPLE.trim_end()).unwrap();
+        assert_eq!(SyntaxScoring::part1(&input), 26397);
+    }
+
+    #[test]
+    fn solves_example_part2() {
+        let input = SyntaxScoring::parse(EXAMPLE.trim_end()).unwrap();
+        assert_eq!(SyntaxScoring::part2(&input), 288957);
+    }
+
+    #[test]
+    fn rejects_lines_it_cannot_score() {
+        let raw_input = "[(\n())";
+        let err = SyntaxScoring::parse(raw_input).map(|_| ()).unwrap_err().locate(raw_input);
+        assert_eq!(err.to_string(), "<input>:2:3: closing bracket with nothing open, found \")\"");
+
+        let raw_input = "[(\n()";
+        let err = SyntaxScoring::parse(raw_input).map(|_| ()).unwrap_err().locate(raw_input);
+        assert_eq!(err.to_string(), "<input>:2:1: expected a corrupted or incomplete line, found \"()\"");
+
+        let raw_input = "(]";
+        let err = SyntaxScoring::parse(raw_input).map(|_| ()).unwrap_err().locate(raw_input);
+        assert_eq!(err.to_string(), "<input>:1:1: expected at least one incomplete line, found \"(]\"");
+
+        let raw_input = "(x";
+        let err = SyntaxScoring::parse(raw_input).map(|_| ()).unwrap_err().locate(raw_input);
+        assert_eq!(err.to_string(), "<input>:1:2: expected only brackets, found \"x\"");
+    }
+
+    #[test]
+    fn scores_deep_nesting() {
+        let deep_line = "(".repeat(27);
+        let input = SyntaxScoring::parse(&deep_line).unwrap();
+        assert_eq!(SyntaxScoring::part1(&input), 0);
+        assert_eq!(SyntaxScoring::part2(&input), (5u64.pow(27) - 1) / 4);
+
+        let too_deep_line = "(".repeat(300);
+        assert!(SyntaxScoring::parse(&too_deep_line).is_err());
+    }
+}
@@ -0,0 +1,140 @@
+use std::collections::HashSet;
+use std::io::{self, IsTerminal};
+
+use crate::shared::grid::Grid;
+use crate::shared::positions::Position;
+use crate::shared::render::{render_ansi, render_text, Rgb};
+use crate::error::Result;
+use crate::logging::{self, Verbosity};
+use crate::{debug_log, trace_log};
+use crate::solution::Solution;
+
+const FLASH_THRESHOLD: u8 = 9;
+const FLASH_COLOR: Rgb = Rgb(255, 215, 0);
+
+fn energy_char(energy_level: &u8) -> char {
+    char::from_digit(*energy_level as u32, 10).unwrap_or('+')
+}
+
+pub struct Dumbos {
+    grid: Grid<u8>,
+    num_flashes: u32,
+}
+
+impl Clone for Dumbos {
+    fn clone(&self) -> Dumbos {
+        Dumbos { grid: self.grid.clone(), num_flashes: 0 }
+    }
+}
+
+impl Dumbos {
+    fn debug_print(&self) {
+        if !logging::enabled(Verbosity::Trace) {
+            return;
+        }
+        // Octopi that just flashed are back at 0, so those are the ones highlighted
+        let rendered_grid = match io::stderr().is_terminal() {
+            true => render_ansi(&self.grid, energy_char, |energy_level| (*energy_level == 0).then_some(FLASH_COLOR)),
+            false => render_text(&self.grid, energy_char),
+        };
+        trace_log!("{}num flashes so far: {}", rendered_grid, self.num_flashes);
+    }
+
+    fn flash_dfs(&mut self, initial_flashes: &[Position]) {
+        let mut flash_stack: Vec<Position> = initial_flashes.to_vec();
+        let mut flashed: HashSet<Position> = initial_flashes.iter().copied().collect();
+        while let Some(curr_flashed_position) = flash_stack.pop() {
+            for neighbor_pos in self.grid.all_neighbors(curr_flashed_position) {
+                self.grid[neighbor_pos] += 1;
+                if !flashed.contains(&neighbor_pos) && self.grid[neighbor_pos] > FLASH_THRESHOLD {
+                    flashed.insert(neighbor_pos);
+                    flash_stack.push(neighbor_pos);
+                }
+            }
+        }
+    }
+
+    fn reset_flashes_and_is_sync(&mut self) -> bool {
+        // Reset the flashes found, add them to the total number, and return true if all flashed
+        let mut all_flashed = true;
+
+        for energy_level in self.grid.iter_mut() {
+            if *energy_level > FLASH_THRESHOLD {
+                *energy_level = 0;
+                self.num_flashes += 1;
+            } else {
+                all_flashed = false;
+            }
+        }
+        all_flashed
+    }
+
+    fn step_and_is_sync(&mut self) -> bool {
+        let mut initial_flashes: Vec<Position> = Vec::new();
+        for position in self.grid.positions() {
+            self.grid[position] += 1;
+            if self.grid[position] > FLASH_THRESHOLD {
+                initial_flashes.push(position);
+            }
+        }
+        self.flash_dfs(&initial_flashes);
+        self.reset_flashes_and_is_sync()
+    }
+}
+
+fn get_dumbos(file_contents: &str) -> Result<Dumbos> {
+    Ok(Dumbos { grid: Grid::parse_digits(file_contents)?, num_flashes: 0 })
+}
+
+pub struct DumboOctopus;
+
+impl Solution for DumboOctopus {
+    const DAY: u8 = 11;
+
+    type Input = Dumbos;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(raw_input: &str) -> Result<Dumbos> {
+        get_dumbos(raw_input)
+    }
+
+    fn part1(dumbos: &Dumbos) -> u32 {
+        let mut dumbos_clone = dumbos.clone();
+        dumbos_clone.debug_print();
+        for _ in 0..100 {
+            dumbos_clone.step_and_is_sync();
+            dumbos_clone.debug_print();
+        }
+        dumbos_clone.num_flashes
+    }
+
+    fn part2(dumbos: &Dumbos) -> u32 {
+        let mut dumbos_clone = dumbos.clone();
+        let mut step_counter: u32 = 1;
+        while !dumbos_clone.step_and_is_sync() {
+            debug_log!("Step: {} done. Not in sync", step_counter);
+            step_counter += 1;
+        }
+        step_counter
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../inputs/i11_example.txt");
+
+    #[test]
+    fn solves_example_part1() {
+        let input = DumboOctopus::parse(EXAMPLE.trim_end()).unwrap();
+        assert_eq!(DumboOctopus::part1(&input), 1656);
+    }
+
+    #[test]
+    fn solves_example_part2() {
+        let input = DumboOctopus::parse(EXAMPLE.trim_end()).unwrap();
+        assert_eq!(DumboOctopus::part2(&input), 195);
+    }
+}
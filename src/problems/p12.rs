@@ -0,0 +1,215 @@
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use crate::error::{Error, Result};
+use crate::shared::parse::{lines, pair};
+use crate::solution::Solution;
+
+enum CaveType {
+    Large,
+    Small,
+    StartPoint,
+    EndPoint,
+}
+
+impl From<&String> for CaveType {
+    fn from(cave_name: &String) -> Self {
+        if cave_name == "end" {
+            CaveType::EndPoint
+        } else if cave_name == "start" {
+            CaveType::StartPoint
+        }
+        else if cave_name.chars().any(|c| c.is_ascii_lowercase()) {
+            CaveType::Small
+        } else {
+            CaveType::Large
+        }
+    }
+}
+
+const START_POINT: &str = "start";
+
+struct PathSet {
+    path: Vec<String>,
+    small_caves_visited: HashSet<String>,
+    has_double_dipped: bool,
+    current_cave: String,
+}
+
+impl PathSet {
+    fn new() -> PathSet {
+        let start = String::from(START_POINT);
+        PathSet {
+            path: vec![start.clone()],
+            small_caves_visited: HashSet::from([start.clone()]),
+            has_double_dipped: false,
+            current_cave: start.clone(),
+        }
+    }
+
+    fn copy_with_insert(&self, latest_cave: &String) -> PathSet {
+        // Return a value of a new PathSet that includes the latest cave passed in
+        let mut path = self.path.clone();
+        path.push(latest_cave.clone());
+        let mut small_caves_visited = self.small_caves_visited.clone();
+        let mut has_double_dipped = self.has_double_dipped;
+        if let CaveType::Small = CaveType::from(latest_cave) {
+            if !small_caves_visited.insert(latest_cave.clone()) { has_double_dipped = true; }
+        }
+        let current_cave = latest_cave.clone();
+        PathSet{ path, small_caves_visited, has_double_dipped, current_cave }
+    }
+}
+
+pub struct CaveGraph {
+    edges: HashMap<String, HashSet<String>>,
+}
+
+impl TryFrom<Vec<&str>> for CaveGraph {
+    type Error = Error;
+
+    fn try_from(edge_strings: Vec<&str>) -> Result<Self> {
+        let mut all_edges: HashMap<String, HashSet<String>> = HashMap::new();
+
+        for edge_string in edge_strings.iter() {
+            let (cave_1, cave_2) = pair(edge_string, "-")?;
+            for cave in [cave_1, cave_2] {
+                if cave.is_empty() || !cave.chars().all(|c| c.is_ascii_alphabetic()) {
+                    return Err(Error::parse(cave, "expected a cave name made of letters"));
+                }
+            }
+            let cave_1 = cave_1.to_string();
+            let cave_2 = cave_2.to_string();
+            // Two large caves side by side can be walked back and forth forever
+            if let (CaveType::Large, CaveType::Large) = (CaveType::from(&cave_1), CaveType::from(&cave_2)) {
+                return Err(Error::parse(edge_string, "large caves can't connect to each other"));
+            }
+            match all_edges.get(&cave_1) {
+                Some(cave_edge_set) => {
+                    let mut cave_edge_set_updated = cave_edge_set.clone();
+                    cave_edge_set_updated.insert(cave_2.clone());
+                    all_edges.insert(cave_1.clone(), cave_edge_set_updated);
+                },
+                None => {
+                    all_edges.insert(cave_1.clone(), HashSet::from([cave_2.clone()]));
+                },
+            }
+            match all_edges.get(&cave_2) {
+                Some(cave_edge_set) => {
+                    let mut cave_edge_set_updated = cave_edge_set.clone();
+                    cave_edge_set_updated.insert(cave_1.clone());
+                    all_edges.insert(cave_2.clone(), cave_edge_set_updated);
+                },
+                None => {
+                    all_edges.insert(cave_2.clone(), HashSet::from([cave_1.clone()]));
+                },
+            }
+        }
+
+        if !all_edges.contains_key(START_POINT) {
+            return Err(Error::parse(edge_strings[0], "expected a passage from the start cave"));
+        }
+        Ok(CaveGraph{ edges: all_edges })
+    }
+}
+
+impl CaveGraph {
+    fn find_all_paths(&self, with_double_dip: bool) -> Vec<PathSet> {
+        let mut search_stack: Vec<PathSet> = vec![
+            PathSet::new()
+        ];
+        let mut valid_path_sets: Vec<PathSet> = Vec::new();
+        while let Some(search_vertex) = search_stack.pop() {
+            // Parsing checks the start cave has edges, and every other cave is only reached
+            // through an edge, which is recorded in both directions
+            for connecting_cave in self.edges[&search_vertex.current_cave].iter() {
+                match CaveType::from(connecting_cave) {
+                    CaveType::EndPoint => {
+                        let valid_path_set = search_vertex.copy_with_insert(connecting_cave);
+                        valid_path_sets.push(valid_path_set);
+                    },
+                    CaveType::StartPoint => (),
+                    _ => {
+                        if !search_vertex.small_caves_visited.contains(connecting_cave) || (
+                            with_double_dip && !search_vertex.has_double_dipped
+                        ) {
+                            let next_search = search_vertex.copy_with_insert(connecting_cave);
+                            search_stack.push(next_search);
+                        }
+                    },
+                }
+            }
+        }
+
+        valid_path_sets
+    }
+}
+
+fn get_cave_graph(file_contents: &str) -> Result<CaveGraph> {
+    let file_as_str_vec: Vec<&str> = lines(file_contents).collect();
+    CaveGraph::try_from(file_as_str_vec)
+}
+
+pub struct PassagePathing;
+
+impl Solution for PassagePathing {
+    const DAY: u8 = 12;
+
+    type Input = CaveGraph;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(raw_input: &str) -> Result<CaveGraph> {
+        get_cave_graph(raw_input)
+    }
+
+    fn part1(cave_graph: &CaveGraph) -> usize {
+        cave_graph.find_all_paths(false).len()
+    }
+
+    fn part2(cave_graph: &CaveGraph) -> usize {
+        cave_graph.find_all_paths(true).len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../inputs/i12_example.txt");
+
+    #[test]
+    fn solves_example_part1() {
+        let input = PassagePathing::parse(EXAMPLE.trim_end()).unwrap();
+        assert_eq!(PassagePathing::part1(&input), 10);
+    }
+
+    #[test]
+    fn solves_example_part2() {
+        let input = PassagePathing::parse(EXAMPLE.trim_end()).unwrap();
+        assert_eq!(PassagePathing::part2(&input), 36);
+    }
+
+    #[test]
+    fn solves_larger_examples() {
+        let slightly_larger = PassagePathing::parse(
+            "dc-end\nHN-start\nstart-kj\ndc-start\ndc-HN\nLN-dc\nHN-end\nkj-sa\nkj-HN\nkj-dc"
+        ).unwrap();
+        assert_eq!(PassagePathing::part1(&slightly_larger), 19);
+        assert_eq!(PassagePathing::part2(&slightly_larger), 103);
+    }
+
+    #[test]
+    fn rejects_adjacent_large_caves() {
+        let raw_input = "start-A\nA-B\nB-end";
+        let err = PassagePathing::parse(raw_input).map(|_| ()).unwrap_err().locate(raw_input);
+        assert_eq!(err.to_string(), "<input>:2:1: large caves can't connect to each other, found \"A-B\"");
+    }
+
+    #[test]
+    fn rejects_graph_without_start() {
+        let raw_input = "a-b\nb-end";
+        let err = PassagePathing::parse(raw_input).map(|_| ()).unwrap_err().locate(raw_input);
+        assert_eq!(err.to_string(), "<input>:1:1: expected a passage from the start cave, found \"a-b\"");
+    }
+}
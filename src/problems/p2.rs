@@ -0,0 +1,381 @@
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use crate::debug_log;
+use crate::error::{Error, Result};
+use crate::logging::{self, Verbosity};
+use crate::shared::grid::Grid;
+use crate::shared::parse::{lines, pair, parse_token};
+use crate::shared::positions::{Direction, Position as GridPosition};
+use crate::shared::render::render_text;
+use crate::solution::Solution;
+
+// A submarine program, one command per line:
+//   forward N, back N, down N, up N   move along the surface or change depth
+//   reset-aim                         level the submarine out again
+//   repeat N {                        run the lines up to the matching `}` N times
+//   # ...                             a comment, on its own line or after a command
+// Forward heads east along the surface and depth grows southwards, so down is south
+#[derive(Debug)]
+pub enum Instruction {
+    Move { direction: Direction, distance: u32 },
+    ResetAim,
+    Repeat { times: u32, body: Vec<Instruction> },
+}
+
+fn parse_command(line: &str) -> Result<Instruction> {
+    if line == "reset-aim" {
+        return Ok(Instruction::ResetAim);
+    }
+    let (command, distance_str) = pair(line, " ")?;
+    let direction = match command {
+        "forward" => Direction::EAST,
+        "back" => Direction::WEST,
+        "down" => Direction::SOUTH,
+        "up" => Direction::NORTH,
+        bad_instr => return Err(Error::parse(bad_instr, "bad instruction")),
+    };
+    let distance: u32 = parse_token::<u32>(distance_str.trim())?;
+    Ok(Instruction::Move { direction, distance })
+}
+
+// The total distance a course may cover, counting every pass through repeat blocks. Positions
+// are i128, and with at most 2^40 of travel no coordinate can pass 2^80 and no answer 2^120, so
+// no model that moves by at most the distance given can overflow.
+const MAX_TRAVEL: u128 = 1 << 40;
+
+// The number of moves and aim resets a course may run, counting every pass through repeat
+// blocks. Zero-distance steps travel nowhere but still take time, and a trace keeps one position
+// for each of them.
+const MAX_STEPS: u128 = 1 << 20;
+
+// The travel and the number of steps one instruction runs, saturating rather than overflowing
+// since anything near u128::MAX is far past either limit
+fn get_cost(instruction: &Instruction) -> (u128, u128) {
+    match instruction {
+        Instruction::Move { distance, .. } => (*distance as u128, 1),
+        Instruction::ResetAim => (0, 1),
+        Instruction::Repeat { times, body } => {
+            let (body_travel, body_steps) = body.iter().fold((0u128, 0u128), |(travel, steps), body_instruction| {
+                let (instruction_travel, instruction_steps) = get_cost(body_instruction);
+                (travel.saturating_add(instruction_travel), steps.saturating_add(instruction_steps))
+            });
+            (body_travel.saturating_mul(*times as u128), body_steps.saturating_mul(*times as u128))
+        },
+    }
+}
+
+// Open repeat blocks are kept on a stack, each with its header line so an unclosed block can
+// be reported where it starts
+fn read_instructions_from_input(file_contents: &str) -> Result<Vec<Instruction>> {
+    let mut program: Vec<Instruction> = Vec::new();
+    let mut open_blocks: Vec<(&str, u32, Vec<Instruction>)> = Vec::new();
+    let (mut total_travel, mut total_steps) = (0u128, 0u128);
+    for raw_line in lines(file_contents) {
+        let line = raw_line.split('#').next().unwrap().trim();
+        if line.is_empty() {
+            continue;
+        }
+        let (instruction, instruction_line) = if line == "}" {
+            let (block_header, times, body) = open_blocks.pop()
+                .ok_or_else(|| Error::parse(line, "`}` without a matching repeat"))?;
+            (Instruction::Repeat { times, body }, block_header)
+        } else if let Some(block_header) = line.strip_prefix("repeat ") {
+            let times_token = block_header.strip_suffix('{')
+                .ok_or_else(|| Error::parse(line, "expected `repeat N {`"))?;
+            open_blocks.push((line, parse_token::<u32>(times_token.trim())?, Vec::new()));
+            continue;
+        } else {
+            (parse_command(line)?, line)
+        };
+        match open_blocks.last_mut() {
+            Some((_, _, body)) => body.push(instruction),
+            None => {
+                let (travel, steps) = get_cost(&instruction);
+                total_travel = total_travel.saturating_add(travel);
+                total_steps = total_steps.saturating_add(steps);
+                if total_travel > MAX_TRAVEL {
+                    return Err(Error::parse(instruction_line, format!("course travels further than {}", MAX_TRAVEL)));
+                }
+                if total_steps > MAX_STEPS {
+                    return Err(Error::parse(instruction_line, format!("course runs more than {} steps", MAX_STEPS)));
+                }
+                program.push(instruction);
+            },
+        }
+    }
+    match open_blocks.last() {
+        Some((block_header, ..)) => Err(Error::parse(block_header, "repeat block is never closed")),
+        None => Ok(program),
+    }
+}
+
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Position {
+    pub horizontal: i128,
+    pub depth: i128,
+    pub aim: i128,
+}
+
+// How a move changes the submarine's position. `horizontal` is along the surface and
+// `vertical` is downwards, so up is negative.
+pub trait MovementModel {
+    fn get_new_position(&self, position: Position, horizontal: i128, vertical: i128) -> Position;
+}
+
+// Down and up change the depth directly (part 1)
+pub struct DirectModel;
+
+impl MovementModel for DirectModel {
+    fn get_new_position(&self, position: Position, horizontal: i128, vertical: i128) -> Position {
+        Position { horizontal: position.horizontal + horizontal, depth: position.depth + vertical, aim: position.aim }
+    }
+}
+
+// Down and up tilt the submarine, and moving along the surface dives by the aim (part 2)
+pub struct AimModel;
+
+impl MovementModel for AimModel {
+    fn get_new_position(&self, position: Position, horizontal: i128, vertical: i128) -> Position {
+        Position {
+            horizontal: position.horizontal + horizontal,
+            depth: position.depth + horizontal * position.aim,
+            aim: position.aim + vertical,
+        }
+    }
+}
+
+// Wraps another model so the submarine can't rise above the surface
+pub struct SurfaceBoundModel<M>(pub M);
+
+impl<M: MovementModel> MovementModel for SurfaceBoundModel<M> {
+    fn get_new_position(&self, position: Position, horizontal: i128, vertical: i128) -> Position {
+        let new_position = self.0.get_new_position(position, horizontal, vertical);
+        Position { depth: new_position.depth.max(0), ..new_position }
+    }
+}
+
+impl Position {
+    pub fn process_instruction(self, instruction: &Instruction, model: &impl MovementModel) -> Position {
+        self.process_traced(instruction, model, None)
+    }
+
+    // Moves inside a repeat block are recorded on every pass, so the trajectory holds one
+    // position per move actually made
+    fn process_traced(
+        self, instruction: &Instruction, model: &impl MovementModel, mut trajectory: Option<&mut Trajectory>
+    ) -> Position {
+        let new_position = match instruction {
+            Instruction::Move { direction, distance } => {
+                let distance = *distance as i128;
+                model.get_new_position(self, direction.1 as i128 * distance, direction.0 as i128 * distance)
+            },
+            Instruction::ResetAim => Position { aim: 0, ..self },
+            Instruction::Repeat { times, body } => {
+                return (0..*times).fold(self, |pos, _| pos.run_traced_into(body, model, trajectory.as_deref_mut()));
+            },
+        };
+        if let Some(trajectory) = trajectory {
+            trajectory.positions.push(new_position);
+        }
+        new_position
+    }
+
+    fn run_traced_into(
+        self, instructions: &[Instruction], model: &impl MovementModel, mut trajectory: Option<&mut Trajectory>
+    ) -> Position {
+        instructions.iter()
+            .fold(self, | pos, instruction | pos.process_traced(instruction, model, trajectory.as_deref_mut()))
+    }
+
+    pub fn run(self, instructions: &[Instruction], model: &impl MovementModel) -> Position {
+        self.run_traced_into(instructions, model, None)
+    }
+
+    // Like run, but keeps every position along the way
+    pub fn run_traced(self, instructions: &[Instruction], model: &impl MovementModel) -> Trajectory {
+        let mut trajectory = Trajectory { positions: vec![self] };
+        self.run_traced_into(instructions, model, Some(&mut trajectory));
+        trajectory
+    }
+}
+
+// The starting position followed by the position after each instruction
+pub struct Trajectory {
+    pub positions: Vec<Position>,
+}
+
+impl Trajectory {
+    pub fn final_position(&self) -> Position {
+        *self.positions.last().unwrap()
+    }
+
+    pub fn write_csv(&self, writer: &mut impl Write) -> io::Result<()> {
+        writeln!(writer, "step,horizontal,depth,aim")?;
+        for (step, position) in self.positions.iter().enumerate() {
+            writeln!(writer, "{},{},{},{}", step, position.horizontal, position.depth, position.aim)?;
+        }
+        Ok(())
+    }
+
+    pub fn save_csv(&self, path: &Path) -> Result<()> {
+        let io_error = |source: io::Error| Error::Io { path: path.to_path_buf(), source };
+        let mut writer = BufWriter::new(File::create(path).map_err(io_error)?);
+        self.write_csv(&mut writer).and_then(|_| writer.flush()).map_err(io_error)
+    }
+
+    // Depth (downwards) against horizontal distance, scaled to fit `width` x `height` characters.
+    // Each row is labelled with the depth it stands for. Both sizes are at least one character.
+    pub fn render_profile(&self, width: usize, height: usize) -> String {
+        let (width, height) = (width.max(1), height.max(1));
+        let (min_horizontal, max_horizontal) = min_max(self.positions.iter().map(|position| position.horizontal));
+        let (min_depth, max_depth) = min_max(self.positions.iter().map(|position| position.depth));
+        let scale = |value: i128, min_value: i128, max_value: i128, cells: usize| {
+            ((value - min_value) * (cells - 1) as i128 / (max_value - min_value).max(1)) as usize
+        };
+
+        let mut profile: Grid<char> = Grid::new(height, width, ' ');
+        for position in self.positions.iter() {
+            let row = scale(position.depth, min_depth, max_depth, height);
+            let col = scale(position.horizontal, min_horizontal, max_horizontal, width);
+            profile[GridPosition(row, col)] = '*';
+        }
+
+        let label_width = min_depth.to_string().len().max(max_depth.to_string().len());
+        render_text(&profile, |cell| *cell).lines().enumerate()
+            .map(|(row, profile_row)| {
+                let row_depth = min_depth + (max_depth - min_depth) * row as i128 / (height - 1).max(1) as i128;
+                format!("{:>label_width$} |{}\n", row_depth, profile_row)
+            })
+            .collect()
+    }
+}
+
+fn min_max(values: impl Iterator<Item = i128>) -> (i128, i128) {
+    values.fold((i128::MAX, i128::MIN), |(min_value, max_value), value| (min_value.min(value), max_value.max(value)))
+}
+
+const PROFILE_WIDTH: usize = 72;
+const PROFILE_HEIGHT: usize = 16;
+
+pub struct Dive;
+
+impl Solution for Dive {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Instruction>;
+    type Answer1 = i128;
+    type Answer2 = i128;
+
+    fn parse(raw_input: &str) -> Result<Vec<Instruction>> {
+        read_instructions_from_input(raw_input)
+    }
+
+    fn part1(instructions: &Vec<Instruction>) -> i128 {
+        let final_position = Position::default().run(instructions, &DirectModel);
+        final_position.horizontal * final_position.depth
+    }
+
+    fn part2(instructions: &Vec<Instruction>) -> i128 {
+        let final_position = match logging::enabled(Verbosity::Debug) {
+            true => {
+                let trajectory = Position::default().run_traced(instructions, &AimModel);
+                debug_log!("{}", trajectory.render_profile(PROFILE_WIDTH, PROFILE_HEIGHT));
+                trajectory.final_position()
+            },
+            false => Position::default().run(instructions, &AimModel),
+        };
+        final_position.horizontal * final_position.depth
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../inputs/i2_example.txt");
+
+    #[test]
+    fn solves_example_part1() {
+        let input = Dive::parse(EXAMPLE.trim_end()).unwrap();
+        assert_eq!(Dive::part1(&input), 150);
+    }
+
+    #[test]
+    fn solves_example_part2() {
+        let input = Dive::parse(EXAMPLE.trim_end()).unwrap();
+        assert_eq!(Dive::part2(&input), 900);
+    }
+
+    #[test]
+    fn runs_extended_commands() {
+        let program = read_instructions_from_input(
+            "# dive in steps\nrepeat 3 {\n  forward 2\n  repeat 2 {\n    down 1 # tilt\n  }\n}\nback 1\nreset-aim\nforward 4\n\nup 50"
+        ).unwrap();
+        assert_eq!(Position::default().run(&program, &DirectModel), Position { horizontal: 9, depth: -44, aim: 0 });
+        // Each pass dives at the aim from the passes before, 0 + 4 + 8, then back 1 undoes 6 of it
+        assert_eq!(Position::default().run(&program, &AimModel), Position { horizontal: 9, depth: 6, aim: -50 });
+        assert_eq!(Position::default().run(&program, &SurfaceBoundModel(DirectModel)).depth, 0);
+    }
+
+    #[test]
+    fn tracks_far_beyond_i64() {
+        let input = Dive::parse("down 4000000000\nforward 4000000000").unwrap();
+        assert_eq!(Dive::part2(&input), 4_000_000_000 * 4_000_000_000 * 4_000_000_000);
+
+        let raw_input = "forward 1\nrepeat 4000000000 {\n  repeat 4000000000 {\n    forward 1\n  }\n}";
+        let err = read_instructions_from_input(raw_input).unwrap_err().locate(raw_input);
+        assert_eq!(err.to_string(), "<input>:2:1: course travels further than 1099511627776, found \"repeat 4000000000 {\"");
+
+        let raw_input = "repeat 4000000000 {\n  repeat 4000000000 {\n    reset-aim\n  }\n}";
+        let err = read_instructions_from_input(raw_input).unwrap_err().locate(raw_input);
+        assert_eq!(err.to_string(), "<input>:1:1: course runs more than 1048576 steps, found \"repeat 4000000000 {\"");
+    }
+
+    #[test]
+    fn reports_errors_by_line() {
+        let raw_input = "forward 5\nsideways x";
+        let err = read_instructions_from_input(raw_input).unwrap_err().locate(raw_input);
+        assert_eq!(err.to_string(), "<input>:2:1: bad instruction, found \"sideways\"");
+
+        let raw_input = "down 1\nrepeat 2 {\n  forward 1";
+        let err = read_instructions_from_input(raw_input).unwrap_err().locate(raw_input);
+        assert_eq!(err.to_string(), "<input>:2:1: repeat block is never closed, found \"repeat 2 {\"");
+
+        let raw_input = "down 1\n  }";
+        let err = read_instructions_from_input(raw_input).unwrap_err().locate(raw_input);
+        assert_eq!(err.to_string(), "<input>:2:3: `}` without a matching repeat, found \"}\"");
+    }
+
+    #[test]
+    fn traces_every_instruction() {
+        let program = read_instructions_from_input("forward 2\nrepeat 2 {\n  down 1\n}\nforward 3").unwrap();
+        let trajectory = Position::default().run_traced(&program, &AimModel);
+        let depths: Vec<(i128, i128, i128)> = trajectory.positions.iter()
+            .map(|position| (position.horizontal, position.depth, position.aim))
+            .collect();
+        assert_eq!(depths, vec![(0, 0, 0), (2, 0, 0), (2, 0, 1), (2, 0, 2), (5, 6, 2)]);
+        assert_eq!(trajectory.final_position(), Position::default().run(&program, &AimModel));
+
+        let mut csv_bytes = Vec::new();
+        trajectory.write_csv(&mut csv_bytes).unwrap();
+        let csv = String::from_utf8(csv_bytes).unwrap();
+        assert!(csv.starts_with("step,horizontal,depth,aim\n0,0,0,0\n1,2,0,0\n"));
+        assert!(csv.ends_with("4,5,6,2\n"));
+
+        let csv_path = std::env::temp_dir().join(format!("aoc-trace-{}.csv", std::process::id()));
+        trajectory.save_csv(&csv_path).unwrap();
+        assert_eq!(std::fs::read_to_string(&csv_path).unwrap(), csv);
+        std::fs::remove_file(&csv_path).unwrap();
+    }
+
+    #[test]
+    fn renders_depth_profile() {
+        let input = Dive::parse(EXAMPLE.trim_end()).unwrap();
+        let profile = Position::default().run_traced(&input, &AimModel).render_profile(16, 4);
+        assert_eq!(profile, " 0 |*    *          \n20 |                \n40 |             *  \n60 |               *\n");
+        let squeezed_profile = Position::default().run_traced(&input, &AimModel).render_profile(0, 0);
+        assert_eq!(squeezed_profile, " 0 |*\n");
+    }
+}
@@ -0,0 +1,194 @@
+use crate::error::{Error, Result};
+use crate::shared::parse::lines;
+use crate::solution::Solution;
+
+const MAX_BINARY_WIDTH: usize = 64;
+
+// Every reading packed into the low `binary_width` bits of a u64, first digit most significant
+pub struct DiagnosticReport {
+    readings: Vec<u64>,
+    binary_width: usize,
+}
+
+fn read_binary_strings_from_input(file_contents: &str) -> Result<DiagnosticReport> {
+    let first_line = lines(file_contents).next().unwrap();
+    let binary_width = first_line.len();
+    if binary_width == 0 || binary_width > MAX_BINARY_WIDTH {
+        return Err(Error::parse(first_line, format!("expected between 1 and {} binary digits", MAX_BINARY_WIDTH)));
+    }
+    let mut readings = Vec::new();
+    for raw_str in lines(file_contents) {
+        if let Some((bad_index, bad_char)) = raw_str.char_indices().find(|(_, character)| *character != '0' && *character != '1') {
+            return Err(Error::parse(&raw_str[bad_index..bad_index + bad_char.len_utf8()], "non-binary char found"));
+        }
+        if raw_str.len() != binary_width {
+            return Err(Error::parse(raw_str, format!("expected {} binary digits", binary_width)));
+        }
+        readings.push(raw_str.bytes().fold(0u64, |reading, digit| (reading << 1) | (digit - b'0') as u64));
+    }
+    Ok(DiagnosticReport { readings, binary_width })
+}
+
+fn width_mask(binary_width: usize) -> u64 {
+    u64::MAX >> (MAX_BINARY_WIDTH - binary_width)
+}
+
+// How many readings have a 1 at each bit, indexed from the least significant bit. Only the set
+// bits of each reading are visited.
+fn count_ones_per_bit(readings: &[u64], binary_width: usize) -> Vec<usize> {
+    let mut ones_counts = vec![0; binary_width];
+    for reading in readings.iter() {
+        let mut remaining_bits = *reading;
+        while remaining_bits != 0 {
+            ones_counts[remaining_bits.trailing_zeros() as usize] += 1;
+            remaining_bits &= remaining_bits - 1;
+        }
+    }
+    ones_counts
+}
+
+fn count_ones_at(readings: &[u64], bit: usize) -> usize {
+    readings.iter().filter(|reading| (*reading >> bit) & 1 == 1).count()
+}
+
+// Gamma takes the most common bit in each position, with ties going to 1, and epsilon is
+// every other bit
+fn solve_part_1(report: &DiagnosticReport) -> u128 {
+    let ones_counts = count_ones_per_bit(&report.readings, report.binary_width);
+    let gamma_rate = ones_counts.iter().enumerate()
+        .filter(|(_, ones_count)| **ones_count * 2 >= report.readings.len())
+        .fold(0u64, |gamma_rate, (bit, _)| gamma_rate | (1 << bit));
+    let epsilon_rate = !gamma_rate & width_mask(report.binary_width);
+    gamma_rate as u128 * epsilon_rate as u128
+}
+
+// Keeps the readings matching the chosen bit at each position, most significant first, until one
+// is left or the bits run out. `choose_bit` gets the number of ones and zeros among the remaining
+// readings and never picks a bit none of them have, so whatever is left after the last bit is
+// copies of the same reading.
+fn filter_rating(report: &DiagnosticReport, choose_bit: impl Fn(usize, usize) -> u64) -> u64 {
+    let mut filtered_readings = report.readings.clone();
+    for bit in (0..report.binary_width).rev() {
+        if filtered_readings.len() <= 1 {
+            break;
+        }
+        let ones_count = count_ones_at(&filtered_readings, bit);
+        let kept_bit = choose_bit(ones_count, filtered_readings.len() - ones_count);
+        filtered_readings.retain(|reading| (reading >> bit) & 1 == kept_bit);
+    }
+    filtered_readings[0]
+}
+
+// Ties go to 1
+fn most_common_bit(ones_count: usize, zeros_count: usize) -> u64 {
+    (ones_count >= zeros_count) as u64
+}
+
+// Ties go to 0, and when every remaining reading shares the bit that bit is kept
+fn least_common_bit(ones_count: usize, zeros_count: usize) -> u64 {
+    match (ones_count, zeros_count) {
+        (0, _) => 0,
+        (_, 0) => 1,
+        _ => (ones_count < zeros_count) as u64,
+    }
+}
+
+fn solve_part_2(report: &DiagnosticReport) -> u128 {
+    let o2_generator_rating = filter_rating(report, most_common_bit);
+    let co2_scrubber_rating = filter_rating(report, least_common_bit);
+    o2_generator_rating as u128 * co2_scrubber_rating as u128
+}
+
+pub struct BinaryDiagnostic;
+
+impl Solution for BinaryDiagnostic {
+    const DAY: u8 = 3;
+
+    type Input = DiagnosticReport;
+    type Answer1 = u128;
+    type Answer2 = u128;
+
+    fn parse(raw_input: &str) -> Result<DiagnosticReport> {
+        read_binary_strings_from_input(raw_input)
+    }
+
+    fn part1(report: &DiagnosticReport) -> u128 {
+        solve_part_1(report)
+    }
+
+    fn part2(report: &DiagnosticReport) -> u128 {
+        solve_part_2(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../inputs/i3_example.txt");
+
+    #[test]
+    fn solves_example_part1() {
+        let input = BinaryDiagnostic::parse(EXAMPLE.trim_end()).unwrap();
+        assert_eq!(BinaryDiagnostic::part1(&input), 198);
+    }
+
+    #[test]
+    fn solves_example_part2() {
+        let input = BinaryDiagnostic::parse(EXAMPLE.trim_end()).unwrap();
+        assert_eq!(BinaryDiagnostic::part2(&input), 230);
+    }
+
+    #[test]
+    fn packs_readings_up_to_64_bits() {
+        let raw_input = format!("{}\n{}\n1{}", "1".repeat(64), "0".repeat(64), "0".repeat(63));
+        let report = BinaryDiagnostic::parse(&raw_input).unwrap();
+        assert_eq!(report.readings, vec![u64::MAX, 0, 1 << 63]);
+        assert_eq!(count_ones_per_bit(&report.readings, 64)[63], 2);
+        // Gamma is just the top bit, so epsilon is every bit below it
+        assert_eq!(BinaryDiagnostic::part1(&report), (1u128 << 63) * (u64::MAX >> 1) as u128);
+        assert_eq!(filter_rating(&report, most_common_bit), u64::MAX);
+        assert_eq!(filter_rating(&report, least_common_bit), 0);
+
+        let too_wide = "1".repeat(65);
+        assert!(BinaryDiagnostic::parse(&too_wide).is_err());
+    }
+
+    #[test]
+    fn reports_non_binary_chars() {
+        let raw_input = "1011\n10é1";
+        let err = BinaryDiagnostic::parse(raw_input).map(|_| ()).unwrap_err().locate(raw_input);
+        assert_eq!(err.to_string(), "<input>:2:3: non-binary char found, found \"é\"");
+    }
+
+    #[test]
+    fn filters_repeated_readings() {
+        let report = BinaryDiagnostic::parse("00100\n00100\n11110").unwrap();
+        assert_eq!(filter_rating(&report, most_common_bit), 0b00100);
+        assert_eq!(filter_rating(&report, least_common_bit), 0b11110);
+        assert_eq!(BinaryDiagnostic::part2(&report), 4 * 30);
+
+        // 100,000 pseudo-random 12-bit readings, far more than there are distinct values
+        let mut seed: u32 = 12345;
+        let raw_input: Vec<String> = (0..100_000)
+            .map(|_| {
+                seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+                format!("{:012b}", (seed >> 16) & 0xfff)
+            })
+            .collect();
+        let report = BinaryDiagnostic::parse(&raw_input.join("\n")).unwrap();
+        let o2_generator_rating = filter_rating(&report, most_common_bit);
+        let co2_scrubber_rating = filter_rating(&report, least_common_bit);
+        assert!(report.readings.contains(&o2_generator_rating) && report.readings.contains(&co2_scrubber_rating));
+    }
+
+    #[test]
+    fn handles_large_reports() {
+        // Every 18-bit value once, so every bit is tied and the ratings land on the extremes
+        let raw_input: Vec<String> = (0..1u32 << 18).map(|reading| format!("{:018b}", reading)).collect();
+        let report = BinaryDiagnostic::parse(&raw_input.join("\n")).unwrap();
+        assert_eq!(count_ones_per_bit(&report.readings, 18), vec![1 << 17; 18]);
+        assert_eq!(filter_rating(&report, most_common_bit), (1 << 18) - 1);
+        assert_eq!(filter_rating(&report, least_common_bit), 0);
+    }
+}
@@ -0,0 +1,259 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::error::{Error, Result};
+use crate::shared::grid::Grid;
+use crate::shared::parse::{blocks, captures, comma_separated, lines, parse_token};
+use crate::shared::positions::Position;
+use crate::solution::Solution;
+
+const BINGO_BOARD_SIZE: usize = 5;
+
+static BINGO_ROW_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^\s*(\d+)\s+(\d+)\s+(\d+)\s+(\d+)\s+(\d+)\s*$").unwrap()
+});
+
+#[derive(Copy, Clone)]
+struct BingoTile {
+    value: u8,
+    is_hit: bool,
+}
+
+impl BingoTile {
+    fn new() -> BingoTile {
+        BingoTile{ value: 0, is_hit: false }
+    }
+}
+
+#[derive(Clone)]
+struct BingoBoard {
+    grid: Grid<BingoTile>,
+    // Because this is advent of code I have a slight feeling I'm gonna need to optimize for p2,
+    // and that means not checking up to 25 values every time we need to search for a hit.
+    // This value_mapping will allow us the search the board with just one check
+    // for position we want.
+    // We still want to keep the grid above, however, as it will allow fast checking for wins.
+    value_mapping: HashMap<u8, Position>,
+    is_won: bool,
+}
+
+impl BingoBoard {
+    fn hit(&mut self, value: u8) -> Option<Position> {
+        match self.value_mapping.get(&value) {
+            Some(position) => {
+                self.grid[*position].is_hit = true;
+                Some(*position)
+            }
+            _ => None,
+        }
+    }
+
+    fn check_win(&self, hit_position: &Position) -> bool {
+        // Only the row and column through the new hit can have just been completed
+        self.grid.column(hit_position.1).all(|tile| tile.is_hit)
+            || self.grid.row(hit_position.0).iter().all(|tile| tile.is_hit)
+    }
+
+    // Whether some row or column is made up only of drawn values, i.e. the board wins at some point
+    fn can_win(&self, drawn_values: &HashSet<u8>) -> bool {
+        let is_drawn = |tile: &BingoTile| drawn_values.contains(&tile.value);
+        self.grid.rows().any(|row| row.iter().all(is_drawn))
+            || self.grid.columns().any(|mut column| column.all(is_drawn))
+    }
+
+    fn mark_won(&mut self) {
+        self.is_won = true;
+    }
+
+    fn get_board_score(&self, just_hit_value: u8) -> u32 {
+        self.grid.iter()
+            .fold(0, |unmarked_score: u32, tile| {
+                match tile.is_hit {
+                    true => unmarked_score,
+                    false => unmarked_score + tile.value as u32,
+                }
+            })
+            * just_hit_value as u32
+    }
+}
+
+impl TryFrom<&str> for BingoBoard {
+    type Error = Error;
+
+    fn try_from(bingo_str_block: &str) -> Result<Self> {
+        let mut board = BingoBoard {
+            grid: Grid::new(BINGO_BOARD_SIZE, BINGO_BOARD_SIZE, BingoTile::new()),
+            value_mapping: HashMap::new(),
+            is_won: false,
+        };
+
+        let block_lines: Vec<&str> = lines(bingo_str_block).collect();
+        if block_lines.len() != BINGO_BOARD_SIZE {
+            return Err(Error::parse(bingo_str_block, "a bingo block should be exactly 5 lines"));
+        }
+        for (row_index, block_line) in block_lines.into_iter().enumerate() {
+            let tile_strs = captures(&BINGO_ROW_REGEX, block_line, "a bingo row should be 5 numbers")?;
+            for (col_index, tile_str) in tile_strs.into_iter().enumerate() {
+                let tile_value = parse_token::<u8>(tile_str)?;
+                board.grid[Position(row_index, col_index)].value = tile_value;
+                board.value_mapping.insert(tile_value, Position(row_index, col_index));
+            }
+        }
+        Ok(board)
+    }
+}
+
+fn get_draws_and_boards_from_input(file_contents: &str) -> Result<(Vec<u8>, Vec<BingoBoard>)> {
+    let file_contents_components: Vec<&str> = blocks(file_contents).collect();
+    let draws_raw = &file_contents_components[0];
+    let boards_raw = &file_contents_components[1..];
+
+    let draws = comma_separated::<u8>(draws_raw)?;
+    if boards_raw.is_empty() {
+        return Err(Error::parse(draws_raw, "expected at least one board after the draws"));
+    }
+    let boards = boards_raw.iter()
+        .map(|&board_str_block| BingoBoard::try_from(board_str_block)).collect::<Result<Vec<BingoBoard>>>()?;
+    // Part 2 plays until every board has won, so a board that never does leaves no answer
+    let drawn_values: HashSet<u8> = draws.iter().copied().collect();
+    let never_winning_board = boards_raw.iter().zip(boards.iter()).find(|(_, board)| !board.can_win(&drawn_values));
+    if let Some((board_str_block, _)) = never_winning_board {
+        return Err(Error::parse(board_str_block, "this board never wins with these draws"));
+    }
+    Ok((draws, boards))
+}
+
+fn find_winning_board(draws: &[u8], boards: &mut [BingoBoard]) -> Option<(usize, u8)> {
+    for curr_draw in draws.iter() {
+        for (curr_board_index, curr_board) in boards.iter_mut().enumerate() {
+            if let Some(position) = curr_board.hit(*curr_draw) {
+                if curr_board.check_win(&position) {
+                    return Some((curr_board_index, *curr_draw));
+                }
+            }
+        }
+    }
+    None
+}
+
+fn find_final_board(draws: &[u8], boards: &mut [BingoBoard]) -> Option<(usize, u8)> {
+    //init step
+    let mut last_won_index: Option<usize> = None;
+    for curr_draw in draws.iter() {
+        // filter step
+        for (curr_board_index, curr_board) in boards.iter_mut().enumerate() {
+            if !curr_board.is_won {
+                if let Some(position) = curr_board.hit(*curr_draw) {
+                    if curr_board.check_win(&position) {
+                        curr_board.mark_won();
+                        last_won_index = Some(curr_board_index);
+                    }
+                }
+            }
+        }
+        if boards.iter().all(|board| board.is_won) {
+            return Some((last_won_index.unwrap(), *curr_draw));
+        }
+    }
+    None
+}
+
+fn solve_part_1(draws: &[u8], boards: &mut [BingoBoard]) -> u32 {
+    let winning_board_and_draw = find_winning_board(draws, boards);
+    match winning_board_and_draw {
+        None => { panic!("no board index found after all draws!"); }
+        Some((board_index, draw)) => boards[board_index].get_board_score(draw),
+    }
+}
+
+fn solve_part_2(draws: &[u8], boards: &mut [BingoBoard]) -> u32 {
+    let winning_board_and_draw = find_final_board(draws, boards);
+    match winning_board_and_draw {
+        None => { panic!("no board index found after all draws!"); }
+        Some((board_index, draw)) => boards[board_index].get_board_score(draw),
+    }
+}
+
+pub struct BingoGame {
+    draws: Vec<u8>,
+    boards: Vec<BingoBoard>,
+}
+
+pub struct GiantSquid;
+
+impl Solution for GiantSquid {
+    const DAY: u8 = 4;
+
+    type Input = BingoGame;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(raw_input: &str) -> Result<BingoGame> {
+        let (draws, boards) = get_draws_and_boards_from_input(raw_input)?;
+        Ok(BingoGame { draws, boards })
+    }
+
+    fn part1(bingo_game: &BingoGame) -> u32 {
+        // Boards get marked as draws come in, so each part plays on fresh copies
+        let mut boards = bingo_game.boards.clone();
+        solve_part_1(&bingo_game.draws, &mut boards)
+    }
+
+    fn part2(bingo_game: &BingoGame) -> u32 {
+        let mut boards = bingo_game.boards.clone();
+        solve_part_2(&bingo_game.draws, &mut boards)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../inputs/i4_example.txt");
+
+    #[test]
+    fn solves_example_part1() {
+        let input = GiantSquid::parse(EXAMPLE.trim_end()).unwrap();
+        assert_eq!(GiantSquid::part1(&input), 4512);
+    }
+
+    #[test]
+    fn solves_example_part2() {
+        let input = GiantSquid::parse(EXAMPLE.trim_end()).unwrap();
+        assert_eq!(GiantSquid::part2(&input), 1924);
+    }
+
+    #[test]
+    fn parses_bingo_board() {
+        let board = BingoBoard::try_from("22 13 17 11  0\n 8  2 23  4 24\n21  9 14 16  7\n 6 10  3 18  5\n 1 12 20 15 19").unwrap();
+        assert_eq!(board.grid[Position(0, 0)].value, 22);
+        assert_eq!(board.grid[Position(4, 4)].value, 19);
+        assert!(matches!(board.value_mapping.get(&14), Some(Position(2, 2))));
+    }
+
+    #[test]
+    fn rejects_short_bingo_board() {
+        assert!(BingoBoard::try_from("22 13 17 11  0\n 8  2 23  4").is_err());
+    }
+
+    #[test]
+    fn rejects_boards_that_never_win() {
+        let raw_input = "1,2\n\n 1  2  3  4  5\n 6  7  8  9 10\n11 12 13 14 15\n16 17 18 19 20\n21 22 23 24 25";
+        let err = GiantSquid::parse(raw_input).map(|_| ()).unwrap_err().locate(raw_input);
+        assert!(err.to_string().starts_with("<input>:3:1: this board never wins with these draws"));
+
+        let winning_input = "1,2,3,4,5\n\n 1  2  3  4  5\n 6  7  8  9 10\n11 12 13 14 15\n16 17 18 19 20\n21 22 23 24 25";
+        let bingo_game = GiantSquid::parse(winning_input).unwrap();
+        assert_eq!(GiantSquid::part1(&bingo_game), (325 - 15) * 5);
+        assert_eq!(GiantSquid::part2(&bingo_game), (325 - 15) * 5);
+    }
+
+    #[test]
+    fn rejects_draws_without_boards() {
+        let raw_input = "7,4,9";
+        let err = GiantSquid::parse(raw_input).map(|_| ()).unwrap_err().locate(raw_input);
+        assert_eq!(err.to_string(), "<input>:1:1: expected at least one board after the draws, found \"7,4,9\"");
+    }
+}
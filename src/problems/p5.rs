@@ -0,0 +1,141 @@
+use crate::error::{Error, Result};
+use crate::shared::parse::{arrow_pair, map_lines, parse_pair};
+use crate::shared::positions::Point2;
+use crate::shared::sparse::SparseGrid;
+use crate::solution::Solution;
+
+fn get_point(point_str: &str) -> Result<Point2> {
+    let (x, y) = parse_pair::<i64, i64>(point_str, ",")?;
+    Ok(Point2::new(x, y))
+}
+
+pub struct LineSegment {
+    start_point: Point2,
+    end_point: Point2,
+}
+
+impl LineSegment {
+    // One step along the segment; horizontal, vertical and 45 degree lines only need unit steps
+    fn get_increment(&self) -> Point2 {
+        (self.end_point - self.start_point).signum()
+    }
+
+    fn is_diagonal(&self) -> bool {
+        self.start_point.x() != self.end_point.x() && self.start_point.y() != self.end_point.y()
+    }
+}
+
+impl TryFrom<&str> for LineSegment {
+    type Error = Error;
+
+    fn try_from(line_segment_str: &str) -> Result<Self> {
+        let (start_str, end_str) = arrow_pair(line_segment_str)?;
+        let line_segment = LineSegment {
+            start_point: get_point(start_str)?,
+            end_point: get_point(end_str)?,
+        };
+        // Anything steeper or shallower than 45 degrees would never step onto its end point
+        let span = line_segment.end_point - line_segment.start_point;
+        if span.x() != 0 && span.y() != 0 && span.x().abs() != span.y().abs() {
+            return Err(Error::parse(line_segment_str, "expected a horizontal, vertical or 45 degree line"));
+        }
+        Ok(line_segment)
+    }
+}
+
+struct PointsHash {
+    marked_points: SparseGrid<u32>,
+    use_diagonals: bool,
+}
+
+impl PointsHash {
+    fn mark_line(&mut self, line_segment: &LineSegment) {
+        let mut curr_pos = line_segment.start_point;
+        let increment = line_segment.get_increment();
+
+        while curr_pos != line_segment.end_point + increment {
+            *self.marked_points.get_mut(curr_pos) += 1;
+
+            curr_pos += increment;
+        }
+    }
+
+    fn mark_line_set(&mut self, line_segments: &[LineSegment]) {
+        for line_segment in line_segments.iter() {
+            if !line_segment.is_diagonal() || self.use_diagonals {
+                self.mark_line(line_segment);
+            }
+        }
+    }
+
+    fn get_num_points_overlap(&self, threshold: u32) -> u32 {
+        self.marked_points.values().fold(0, |overlap_above_threshold, value| {
+            overlap_above_threshold + if *value >= threshold {
+                1
+            } else {
+                0
+            }
+        })
+    }
+}
+
+fn get_line_segments(file_contents: &str) -> Result<Vec<LineSegment>> {
+    map_lines(file_contents, LineSegment::try_from)
+}
+
+fn solve_both_parts(line_segments: &[LineSegment], use_diagonals: bool) -> u32 {
+    let mut points_hash = PointsHash {
+        marked_points: SparseGrid::new(0),
+        use_diagonals,
+    };
+    points_hash.mark_line_set(line_segments);
+    points_hash.get_num_points_overlap(2)
+}
+
+pub struct HydrothermalVenture;
+
+impl Solution for HydrothermalVenture {
+    const DAY: u8 = 5;
+
+    type Input = Vec<LineSegment>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(raw_input: &str) -> Result<Vec<LineSegment>> {
+        get_line_segments(raw_input)
+    }
+
+    fn part1(line_segments: &Vec<LineSegment>) -> u32 {
+        solve_both_parts(line_segments, false)
+    }
+
+    fn part2(line_segments: &Vec<LineSegment>) -> u32 {
+        solve_both_parts(line_segments, true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../inputs/i5_example.txt");
+
+    #[test]
+    fn solves_example_part1() {
+        let input = HydrothermalVenture::parse(EXAMPLE.trim_end()).unwrap();
+        assert_eq!(HydrothermalVenture::part1(&input), 5);
+    }
+
+    #[test]
+    fn solves_example_part2() {
+        let input = HydrothermalVenture::parse(EXAMPLE.trim_end()).unwrap();
+        assert_eq!(HydrothermalVenture::part2(&input), 12);
+    }
+
+    #[test]
+    fn rejects_uneven_slopes() {
+        let raw_input = "0,9 -> 5,9\n0,0 -> 1,3";
+        let err = HydrothermalVenture::parse(raw_input).map(|_| ()).unwrap_err().locate(raw_input);
+        assert_eq!(err.to_string(), "<input>:2:1: expected a horizontal, vertical or 45 degree line, found \"0,0 -> 1,3\"");
+    }
+}
@@ -1,6 +1,9 @@
 use std::collections::HashMap;
 
-use advent_of_code_2021::inputs::read_input;
+use crate::debug_log;
+use crate::error::Result;
+use crate::shared::parse::comma_separated;
+use crate::solution::Solution;
 
 const LANTERNFISH_CYCLE_START: u8 = 6;
 const LANTERNFISH_NEW_CYCLE_START: u8 = 8;
@@ -11,7 +14,7 @@ struct FishGroup{
     lifecycle: u8,
 }
 
-fn compress_fish_groups(fish_groups: &Vec<FishGroup>) -> Vec<FishGroup> {
+fn compress_fish_groups(fish_groups: &[FishGroup]) -> Vec<FishGroup> {
     let mut lifecycle_to_size_hash: HashMap<u8, usize> = HashMap::new();
     for fish_group in fish_groups {
         let updated_size = fish_group.size + match lifecycle_to_size_hash.get(&fish_group.lifecycle) {
@@ -24,11 +27,11 @@ fn compress_fish_groups(fish_groups: &Vec<FishGroup>) -> Vec<FishGroup> {
         .map(|(lifecycle, size)| FishGroup{ lifecycle: *lifecycle, size: *size}).collect()
 }
 
-fn get_lanternfish_starting_counters(file_contents: String) -> Vec<u8> {
-    file_contents.split(",").map(|counter_str| counter_str.parse::<u8>().unwrap()).collect()
+fn get_lanternfish_starting_counters(file_contents: &str) -> Result<Vec<u8>> {
+    comma_separated::<u8>(file_contents)
 }
 
-fn cycle_lanternfish_one_day(lanternfish: &Vec<FishGroup>) -> Vec<FishGroup> {
+fn cycle_lanternfish_one_day(lanternfish: &[FishGroup]) -> Vec<FishGroup> {
     let mut updated_lanternfish: Vec<FishGroup> = Vec::new();
     let mut fresh_lanternfish: Vec<FishGroup> = Vec::new();
     for fish_group in lanternfish {
@@ -43,30 +46,56 @@ fn cycle_lanternfish_one_day(lanternfish: &Vec<FishGroup>) -> Vec<FishGroup> {
     updated_lanternfish
 }
 
-fn get_lanternfish_counters_after_days(lanternfish_starting_counters: &Vec<u8>, days: u32) -> Vec<FishGroup> {
+fn get_lanternfish_counters_after_days(lanternfish_starting_counters: &[u8], days: u32) -> Vec<FishGroup> {
     let mut lanternfish_groups: Vec<FishGroup> = lanternfish_starting_counters.iter().map(|counter| FishGroup{size: 1, lifecycle: *counter}).collect();
     for day in 0..days {
         lanternfish_groups = compress_fish_groups(&lanternfish_groups);
-        println!("day {}, lanternfish size {}", day,
+        debug_log!("day {}, lanternfish size {}", day,
             lanternfish_groups.iter().fold(0, |fish_sum, lanternfish_group| fish_sum + lanternfish_group.size));
         lanternfish_groups = cycle_lanternfish_one_day(&lanternfish_groups);
     }
     lanternfish_groups
 }
 
-fn solve_part_1(lanternfish_starting_counters: &Vec<u8>) -> usize {
-    let after_80_days = get_lanternfish_counters_after_days(lanternfish_starting_counters, 80);
-    after_80_days.iter().fold(0, |fish_sum, lanternfish_group| fish_sum + lanternfish_group.size)
-}
+pub struct Lanternfish;
+
+impl Solution for Lanternfish {
+    const DAY: u8 = 6;
+
+    type Input = Vec<u8>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(raw_input: &str) -> Result<Vec<u8>> {
+        get_lanternfish_starting_counters(raw_input)
+    }
+
+    fn part1(lanternfish_starting_counters: &Vec<u8>) -> usize {
+        let after_80_days = get_lanternfish_counters_after_days(lanternfish_starting_counters, 80);
+        after_80_days.iter().fold(0, |fish_sum, lanternfish_group| fish_sum + lanternfish_group.size)
+    }
 
-fn solve_part_2(lanternfish_starting_counters: &Vec<u8>) -> usize {
-    let after_256_days = get_lanternfish_counters_after_days(lanternfish_starting_counters, 256);
-    after_256_days.iter().fold(0, |fish_sum, lanternfish_group| fish_sum + lanternfish_group.size)
+    fn part2(lanternfish_starting_counters: &Vec<u8>) -> usize {
+        let after_256_days = get_lanternfish_counters_after_days(lanternfish_starting_counters, 256);
+        after_256_days.iter().fold(0, |fish_sum, lanternfish_group| fish_sum + lanternfish_group.size)
+    }
 }
 
-fn main() {
-    let problem_raw_input = read_input("src/inputs/i6.txt");
-    let lanternfish_starting_counters = get_lanternfish_starting_counters(problem_raw_input);
-    println!("{}", solve_part_1(&lanternfish_starting_counters));
-    println!("{}", solve_part_2(&lanternfish_starting_counters));
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../inputs/i6_example.txt");
+
+    #[test]
+    fn solves_example_part1() {
+        let input = Lanternfish::parse(EXAMPLE.trim_end()).unwrap();
+        assert_eq!(Lanternfish::part1(&input), 5934);
+    }
+
+    #[test]
+    fn solves_example_part2() {
+        let input = Lanternfish::parse(EXAMPLE.trim_end()).unwrap();
+        assert_eq!(Lanternfish::part2(&input), 26984457539);
+    }
 }
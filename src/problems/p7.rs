@@ -0,0 +1,101 @@
+use std::cmp::min;
+
+use crate::error::Result;
+use crate::shared::parse::comma_separated;
+use crate::solution::Solution;
+
+fn get_crabs_sorted(file_contents: &str) -> Result<Vec<i32>> {
+    let mut crabs = comma_separated::<i32>(file_contents)?;
+    crabs.sort_unstable();
+    Ok(crabs)
+}
+
+fn get_median(crabs_list: &[i32]) -> i32 {
+    crabs_list[crabs_list.len() / 2]
+}
+
+fn get_mean(crabs_list: &[i32]) -> i32 {
+    crabs_list.iter().fold(0, |sum, crab| sum + *crab) / crabs_list.len() as i32
+}
+
+fn get_fuel_cost(from: i32, to: i32, triangular: bool) -> i32 {
+    if !triangular{
+        (from - to).abs()
+    } else {
+        (from - to).abs() * ((from - to).abs() + 1) / 2
+    }
+}
+
+fn sum_from_midpoint(crabs_list: &[i32], midpoint: i32, triangular: bool) -> i32 {
+    crabs_list.iter().fold(0, |sum, crab| sum + get_fuel_cost(*crab, midpoint, triangular))
+}
+
+fn min_sum_from_midpoints(
+    crabs_list: &[i32], triangular: bool, prev_midpoint: i32, prev_min_sum: i32, increment: i32
+) -> i32 {
+    let new_midpoint = prev_midpoint + increment;
+    let sum_from_new_midpoint = sum_from_midpoint(crabs_list, new_midpoint, triangular);
+    if sum_from_new_midpoint < prev_min_sum  {
+        min(
+            min_sum_from_midpoints(
+                crabs_list, triangular, new_midpoint, sum_from_new_midpoint, increment
+            ),
+            prev_min_sum
+        )
+    } else {
+        prev_min_sum
+    }
+}
+
+pub struct TreacheryOfWhales;
+
+impl Solution for TreacheryOfWhales {
+    const DAY: u8 = 7;
+
+    type Input = Vec<i32>;
+    type Answer1 = i32;
+    type Answer2 = i32;
+
+    fn parse(raw_input: &str) -> Result<Vec<i32>> {
+        get_crabs_sorted(raw_input)
+    }
+
+    fn part1(crabs_list: &Vec<i32>) -> i32 {
+        let starting_point = get_median(crabs_list);
+        let triangular = false;
+        let starting_sum = sum_from_midpoint(crabs_list, starting_point, triangular);
+        min(
+            min_sum_from_midpoints(crabs_list, triangular, starting_point, starting_sum, -1),
+            min_sum_from_midpoints(crabs_list, triangular, starting_point, starting_sum, 1)
+        )
+    }
+
+    fn part2(crabs_list: &Vec<i32>) -> i32 {
+        let starting_point = get_mean(crabs_list);
+        let triangular = true;
+        let starting_sum = sum_from_midpoint(crabs_list, starting_point, triangular);
+        min(
+            min_sum_from_midpoints(crabs_list, triangular, starting_point, starting_sum, -1),
+            min_sum_from_midpoints(crabs_list, triangular, starting_point, starting_sum, 1)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../inputs/i7_example.txt");
+
+    #[test]
+    fn solves_example_part1() {
+        let input = TreacheryOfWhales::parse(EXAMPLE.trim_end()).unwrap();
+        assert_eq!(TreacheryOfWhales::part1(&input), 37);
+    }
+
+    #[test]
+    fn solves_example_part2() {
+        let input = TreacheryOfWhales::parse(EXAMPLE.trim_end()).unwrap();
+        assert_eq!(TreacheryOfWhales::part2(&input), 168);
+    }
+}
@@ -0,0 +1,233 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::error::{Error, Result};
+use crate::shared::parse::{map_lines, pair};
+use crate::solution::Solution;
+
+struct DisplayDigit {
+    bytes: Vec<u8>,
+}
+
+impl Clone for DisplayDigit {
+    fn clone(&self) -> Self {
+        DisplayDigit { bytes: self.bytes.clone() }
+    }
+}
+
+impl TryFrom<&str> for DisplayDigit {
+    type Error = Error;
+
+    fn try_from(digit_str: &str) -> Result<Self> {
+        if digit_str.is_empty() || !digit_str.bytes().all(|segment| (b'a'..=b'g').contains(&segment)) {
+            return Err(Error::parse(digit_str, "expected a digit made of segments a through g"));
+        }
+        let mut digit_as_bytes = digit_str.as_bytes().to_vec();
+        digit_as_bytes.sort_unstable();
+        Ok(DisplayDigit{ bytes: digit_as_bytes })
+    }
+}
+
+// Each line's wiring is worked out while parsing, so only the decoded output digits are kept
+pub struct DisplayInfo{
+    output: Vec<u8>,
+}
+
+fn get_display_digits(digits_str: &str, expected_len: usize) -> Result<Vec<DisplayDigit>> {
+    let display_digits = digits_str.split(" ").map(DisplayDigit::try_from).collect::<Result<Vec<DisplayDigit>>>()?;
+    if display_digits.len() != expected_len {
+        return Err(Error::parse(digits_str, format!("expected {} digits", expected_len)));
+    }
+    Ok(display_digits)
+}
+
+impl TryFrom<&str> for DisplayInfo{
+    type Error = Error;
+
+    fn try_from(display_line: &str) -> Result<Self> {
+        let (digit_set_str, output_str) = pair(display_line, " | ")?;
+        let digit_map = get_digit_map(digit_set_str, &get_display_digits(digit_set_str, 10)?)?;
+        let output_digits = get_display_digits(output_str, 4)?;
+        let output = output_str.split(" ").zip(output_digits.iter())
+            .map(|(digit_str, display_digit)| {
+                digit_map.get(&display_digit.bytes).copied()
+                    .ok_or_else(|| Error::parse(digit_str, "output digit isn't one of the ten patterns"))
+            })
+            .collect::<Result<Vec<u8>>>()?;
+        Ok(DisplayInfo { output })
+    }
+}
+
+// Logic notes:
+// - 0 has SIX segments
+// - 1 is TWO segments. Always know 1.
+// - 2 is FIVE segments. union of 1 and 2 - set of 2 => F
+// - 3 is FIVE segments
+// - 4 is FOUR segments
+// - 5 is FIVE segments
+// - 6 is SIX segments. Set of 8 - set of 6 => C
+// - 7 is THREE segments. Set of 7's segments - set of 1's segments => A
+// - 8 is SEVEN segments. set of 8 - set of 0 => D
+// - 9 is SIX segments. set of 8 - set of 9 => E
+//        set of 9 - set of 3 => B
+// Final letter not solved for => G
+
+// Step by step:
+// Find 1, 4, 7, 8 because of unique segments.
+// NOT NEEDED: Find A with 7 set - 1 set.
+// Find C and D and E with the three six-segment pieces.
+//      Let CDE = Union - Intersect of the three. Intersect of CDE and 1 is C.
+//      1 - C is F. CDE - C is DE. DE - 4 is E. DE - E is D.
+// Let 6 be the six segment piece without C.
+// Let 9 be the six segment piece without E.
+// Let 0 be the six segment piece without D.
+// Let 2 be the five segment piece without F. Let 3 be ... without E.
+//      Let 5 be ... without C AND without E.
+// (Progress: We know 10 digits, five letters)
+fn get_digit_map(digit_set_str: &str, digit_set: &[DisplayDigit]) -> Result<HashMap<Vec<u8>, u8>> {
+    // Here we implement the logic above. Any step that can't find what it needs means the ten
+    // patterns aren't the ten digits, which is reported against the whole pattern list.
+    let miswired = |message: &str| Error::parse(digit_set_str, message);
+    let find_by_len = |segment_count: usize| {
+        digit_set.iter().find(|unknown_digit| unknown_digit.bytes.len() == segment_count)
+            .ok_or_else(|| miswired(&format!("expected a digit with {} segments", segment_count)))
+    };
+    let one = find_by_len(2)?;
+    let four = find_by_len(4)?;
+    let seven = find_by_len(3)?;
+    let eight = find_by_len(7)?;
+
+    let unknown_six_segments: Vec<DisplayDigit> = digit_set.iter()
+        .filter(|unknown_digit| unknown_digit.bytes.len() == 6).cloned().collect();
+    if unknown_six_segments.len() != 3 {
+        return Err(miswired("expected three six segment digits"));
+    }
+
+    let one_set: HashSet<u8> = one.bytes.clone().into_iter().collect();
+    let four_set: HashSet<u8> = four.bytes.clone().into_iter().collect();
+
+    let unknown_six_segments_sets: Vec<HashSet<u8>> =
+        unknown_six_segments.clone().iter().map(|digit| digit.bytes.clone().into_iter().collect()).collect();
+    let six_segments_union: HashSet<u8> = &(&unknown_six_segments_sets[0] | &unknown_six_segments_sets[1]) | &unknown_six_segments_sets[2];
+    let six_segments_intersection: HashSet<u8> = &(&unknown_six_segments_sets[0] & &unknown_six_segments_sets[1]) & &unknown_six_segments_sets[2];
+    let c_d_e_set = &six_segments_union - &six_segments_intersection;
+    let c_set = &c_d_e_set & &one_set;
+    let d_e_set = &c_d_e_set - &c_set;
+    let e_set = &d_e_set - &four_set;
+    let d_set = &d_e_set - &e_set;
+    let single_segment = |segment_set: HashSet<u8>| match segment_set.len() {
+        1 => Ok(segment_set.into_iter().next().unwrap()),
+        _ => Err(miswired("the six segment digits don't pin down segments c, d and e")),
+    };
+    let c = single_segment(c_set)?;
+    let d = single_segment(d_set)?;
+    let e = single_segment(e_set)?;
+    let find_six_segment = |missing_segment: u8| {
+        unknown_six_segments.iter().find(|digit| !digit.bytes.contains(&missing_segment))
+            .ok_or_else(|| miswired("expected a six segment digit for each of 0, 6 and 9"))
+    };
+    let six = find_six_segment(c)?;
+    let nine = find_six_segment(e)?;
+    let zero = find_six_segment(d)?;
+
+    let unknown_five_segments: Vec<&DisplayDigit> =
+        digit_set.iter().filter(|unknown_digit| unknown_digit.bytes.len() == 5).collect();
+    if unknown_five_segments.len() != 3 {
+        return Err(miswired("expected three five segment digits"));
+    }
+
+    let find_five_segment = |matches_digit: &dyn Fn(&DisplayDigit) -> bool| {
+        unknown_five_segments.iter().find(|digit| matches_digit(digit))
+            .ok_or_else(|| miswired("expected a five segment digit for each of 2, 3 and 5"))
+    };
+    let two = find_five_segment(&|digit| digit.bytes.contains(&e))?;
+    let three = find_five_segment(&|digit| digit.bytes.contains(&c) && !digit.bytes.contains(&e))?;
+    let five = find_five_segment(&|digit| !digit.bytes.contains(&c) && !digit.bytes.contains(&e))?;
+
+    let mut digit_map: HashMap<Vec<u8>, u8> = HashMap::new();
+    digit_map.insert(zero.bytes.clone(), 0);
+    digit_map.insert(one.bytes.clone(), 1);
+    digit_map.insert(two.bytes.clone(), 2);
+    digit_map.insert(three.bytes.clone(), 3);
+    digit_map.insert(four.bytes.clone(), 4);
+    digit_map.insert(five.bytes.clone(), 5);
+    digit_map.insert(six.bytes.clone(), 6);
+    digit_map.insert(seven.bytes.clone(), 7);
+    digit_map.insert(eight.bytes.clone(), 8);
+    digit_map.insert(nine.bytes.clone(), 9);
+    if digit_map.len() != 10 {
+        return Err(miswired("expected ten different patterns"));
+    }
+    Ok(digit_map)
+}
+
+fn get_display_infos(file_contents: &str) -> Result<Vec<DisplayInfo>> {
+    map_lines(file_contents, DisplayInfo::try_from)
+}
+
+pub struct SevenSegmentSearch;
+
+impl Solution for SevenSegmentSearch {
+    const DAY: u8 = 8;
+
+    type Input = Vec<DisplayInfo>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(raw_input: &str) -> Result<Vec<DisplayInfo>> {
+        get_display_infos(raw_input)
+    }
+
+    fn part1(display_infos: &Vec<DisplayInfo>) -> u32 {
+        display_infos.iter().fold(0, |sum_of_easy, display_info| {
+            sum_of_easy + display_info.output.iter()
+                .filter(|digit| matches!(digit, 1 | 4 | 7 | 8))
+                .count() as u32
+        })
+    }
+
+    fn part2(display_infos: &Vec<DisplayInfo>) -> u32 {
+        display_infos.iter().fold(0, |sum_of_outputs, display_info| {
+            sum_of_outputs + display_info.output.iter().fold(0, |single_line_sum, digit| (single_line_sum * 10) + *digit as u32)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../inputs/i8_example.txt");
+
+    #[test]
+    fn solves_example_part1() {
+        let input = SevenSegmentSearch::parse(EXAMPLE.trim_end()).unwrap();
+        assert_eq!(SevenSegmentSearch::part1(&input), 26);
+    }
+
+    #[test]
+    fn solves_example_part2() {
+        let input = SevenSegmentSearch::parse(EXAMPLE.trim_end()).unwrap();
+        assert_eq!(SevenSegmentSearch::part2(&input), 61229);
+    }
+
+    #[test]
+    fn maps_scrambled_digits() {
+        let digit_set_str = "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab";
+        let digit_map = get_digit_map(digit_set_str, &get_display_digits(digit_set_str, 10).unwrap()).unwrap();
+        assert_eq!(digit_map[&DisplayDigit::try_from("ab").unwrap().bytes], 1);
+        assert_eq!(digit_map[&DisplayDigit::try_from("cagedb").unwrap().bytes], 0);
+        let display_info = DisplayInfo::try_from(&*format!("{} | cdfeb fcadb cdfeb cdbaf", digit_set_str)).unwrap();
+        assert_eq!(display_info.output, vec![5, 3, 5, 3]);
+    }
+
+    #[test]
+    fn rejects_miswired_displays() {
+        let raw_input = ["abc"; 10].join(" ") + " | abc abc abc abc";
+        let err = SevenSegmentSearch::parse(&raw_input).map(|_| ()).unwrap_err().locate(&raw_input);
+        assert!(err.to_string().starts_with("<input>:1:1: expected a digit with 2 segments, found \"abc abc"));
+
+        let raw_input = "acedgfb cdfbe gcdfa fbcad dab cefabd cdfgeb eafb cagedb ab | cdfeb fcadb cdfeb cdbag";
+        let err = SevenSegmentSearch::parse(raw_input).map(|_| ()).unwrap_err().locate(raw_input);
+        assert_eq!(err.to_string(), "<input>:1:80: output digit isn't one of the ten patterns, found \"cdbag\"");
+    }
+}
@@ -0,0 +1,75 @@
+use crate::shared::components::label_components;
+use crate::shared::grid::Grid;
+use crate::shared::positions::Position;
+use crate::debug_log;
+use crate::error::Result;
+use crate::solution::Solution;
+
+pub struct HeightMap {
+    grid: Grid<u8>,
+    low_points: Vec<Position>
+}
+
+impl HeightMap {
+    fn new(grid: Grid<u8>) -> HeightMap {
+        let mut low_points = Vec::new();
+        for (position, height) in grid.enumerate() {
+            if !grid.orthogonal_neighbors(position).any(|neighbor_pos| grid[neighbor_pos] <= *height) {
+                low_points.push(position);
+            }
+        }
+
+        HeightMap{ grid, low_points }
+    }
+}
+
+fn get_height_map(file_contents: &str) -> Result<HeightMap> {
+    Ok(HeightMap::new(Grid::parse_digits(file_contents)?))
+}
+
+pub struct SmokeBasin;
+
+impl Solution for SmokeBasin {
+    const DAY: u8 = 9;
+
+    type Input = HeightMap;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(raw_input: &str) -> Result<HeightMap> {
+        get_height_map(raw_input)
+    }
+
+    fn part1(height_map: &HeightMap) -> u32 {
+        height_map.low_points.iter().fold(0, |risk_level_sum, low_point| {
+            risk_level_sum + height_map.grid[*low_point] as u32 + 1
+        } )
+    }
+
+    fn part2(height_map: &HeightMap) -> u32 {
+        // Basins are the regions walled off by height 9, each draining to a single low point
+        let basins = label_components(&height_map.grid, |height| *height < 9);
+        let basin_sizes: Vec<u32> = basins.largest(3).iter().map(|basin| basin.size() as u32).collect();
+        debug_log!("{} basins, largest three: {:?}", basins.components.len(), basin_sizes);
+        basin_sizes.iter().product()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../inputs/i9_example.txt");
+
+    #[test]
+    fn solves_example_part1() {
+        let input = SmokeBasin::parse(EXAMPLE.trim_end()).unwrap();
+        assert_eq!(SmokeBasin::part1(&input), 15);
+    }
+
+    #[test]
+    fn solves_example_part2() {
+        let input = SmokeBasin::parse(EXAMPLE.trim_end()).unwrap();
+        assert_eq!(SmokeBasin::part2(&input), 1134);
+    }
+}
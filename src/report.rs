@@ -0,0 +1,162 @@
+use std::time::{Duration, Instant};
+
+use crate::error::{Error, Result};
+use crate::heap;
+use crate::solution::{Day, Part};
+
+pub struct PartReport {
+    pub day: u8,
+    pub part: Part,
+    pub answer: String,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+    // Most heap in use above what was live before parsing, or None without a CountingAllocator
+    pub peak_heap_bytes: Option<usize>,
+}
+
+// Parses once and solves each requested part, timing both. The parse time is shared by every
+// part's row, and the peak heap covers the parsed input as well as whatever the part allocates.
+pub fn measure_day(day: &Day, raw_input: &str, parts: &[Part]) -> Result<Vec<PartReport>> {
+    let heap_baseline = heap::reset_peak();
+    let parse_start = Instant::now();
+    let parsed_input = day.parse(raw_input)?;
+    let parse_time = parse_start.elapsed();
+    let parse_peak_bytes = heap::peak_bytes().saturating_sub(heap_baseline);
+
+    let part_reports = parts.iter().map(|part| {
+        heap::reset_peak();
+        let solve_start = Instant::now();
+        let answer = parsed_input.solve(*part);
+        let solve_time = solve_start.elapsed();
+        let solve_peak_bytes = heap::peak_bytes().saturating_sub(heap_baseline);
+        PartReport {
+            day: day.number,
+            part: *part,
+            answer,
+            parse_time,
+            solve_time,
+            peak_heap_bytes: heap::is_installed().then_some(parse_peak_bytes.max(solve_peak_bytes)),
+        }
+    }).collect();
+    Ok(part_reports)
+}
+
+pub fn format_bytes(num_bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+    let mut scaled_size = num_bytes as f64;
+    let mut unit_index = 0;
+    while scaled_size >= 1024.0 && unit_index < UNITS.len() - 1 {
+        scaled_size /= 1024.0;
+        unit_index += 1;
+    }
+    match unit_index {
+        0 => format!("{} B", num_bytes),
+        _ => format!("{:.1} {}", scaled_size, UNITS[unit_index]),
+    }
+}
+
+pub fn render_table(part_reports: &[PartReport]) -> String {
+    let mut table = format!("{:>3} {:>4}  {:<16} {:>10} {:>10} {:>10}\n", "day", "part", "answer", "parse", "solve", "peak heap");
+    for part_report in part_reports.iter() {
+        let peak_heap = part_report.peak_heap_bytes.map_or_else(|| String::from("-"), format_bytes);
+        table += &format!(
+            "{:>3} {:>4}  {:<16} {:>10} {:>10} {:>10}\n",
+            part_report.day,
+            part_report.part.number(),
+            part_report.answer,
+            format!("{:.1?}", part_report.parse_time),
+            format!("{:.1?}", part_report.solve_time),
+            peak_heap,
+        );
+    }
+    table
+}
+
+fn json_string(text: &str) -> String {
+    let mut escaped = String::from("\"");
+    for character in text.chars() {
+        match character {
+            '"' => escaped += "\\\"",
+            '\\' => escaped += "\\\\",
+            '\n' => escaped += "\\n",
+            '\r' => escaped += "\\r",
+            '\t' => escaped += "\\t",
+            control if control.is_control() => escaped += &format!("\\u{:04x}", control as u32),
+            other => escaped.push(other),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+// One JSON object per line; answers stay strings because some days' answers overflow a double
+pub fn render_json(part_report: &PartReport) -> String {
+    let peak_heap = part_report.peak_heap_bytes.map_or_else(|| String::from("null"), |num_bytes| num_bytes.to_string());
+    format!(
+        "{{\"day\":{},\"part\":{},\"status\":\"ok\",\"answer\":{},\"parse_ns\":{},\"solve_ns\":{},\"peak_heap_bytes\":{}}}",
+        part_report.day,
+        part_report.part.number(),
+        json_string(&part_report.answer),
+        part_report.parse_time.as_nanos(),
+        part_report.solve_time.as_nanos(),
+        peak_heap,
+    )
+}
+
+pub fn render_json_error(day: u8, part: Part, err: &Error) -> String {
+    format!(
+        "{{\"day\":{},\"part\":{},\"status\":\"error\",\"error\":{}}}",
+        day,
+        part.number(),
+        json_string(&err.to_string()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::problems::get_day;
+
+    #[test]
+    fn measures_each_part() {
+        let day = get_day(1).unwrap();
+        let example = include_str!("inputs/i1_example.txt").trim_end();
+        let part_reports = measure_day(day, example, &Part::ALL).unwrap();
+        let answers: Vec<&str> = part_reports.iter().map(|part_report| part_report.answer.as_str()).collect();
+        assert_eq!(answers, vec!["7", "5"]);
+        assert_eq!(part_reports[0].parse_time, part_reports[1].parse_time);
+
+        let table = render_table(&part_reports);
+        assert_eq!(table.lines().count(), 3);
+        assert!(table.lines().nth(1).unwrap().starts_with("  1    1  7 "));
+    }
+
+    #[test]
+    fn renders_json_lines() {
+        let part_report = PartReport {
+            day: 6,
+            part: Part::Two,
+            answer: String::from("26984457539"),
+            parse_time: Duration::from_micros(3),
+            solve_time: Duration::from_nanos(1500),
+            peak_heap_bytes: None,
+        };
+        assert_eq!(
+            render_json(&part_report),
+            r#"{"day":6,"part":2,"status":"ok","answer":"26984457539","parse_ns":3000,"solve_ns":1500,"peak_heap_bytes":null}"#
+        );
+
+        let err = Error::parse("x\"y", "expected a number");
+        assert_eq!(
+            render_json_error(7, Part::One, &err),
+            r#"{"day":7,"part":1,"status":"error","error":"<input>: expected a number, found \"x\\\"y\""}"#
+        );
+    }
+
+    #[test]
+    fn formats_heap_sizes() {
+        assert_eq!(format_bytes(512), "512 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+    }
+}
@@ -0,0 +1,184 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::error::{Error, Result};
+
+// Starting point for a new day. It builds and its tests are ignored until the example answers
+// are filled in; `{day}` is replaced with the day number.
+const SOLUTION_TEMPLATE: &str = r#"use crate::error::Result;
+use crate::shared::parse::map_lines;
+use crate::solution::Solution;
+
+// TODO: rename after the puzzle title and pick real input and answer types
+pub struct Day{day};
+
+impl Solution for Day{day} {
+    const DAY: u8 = {day};
+
+    type Input = Vec<String>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(raw_input: &str) -> Result<Vec<String>> {
+        map_lines(raw_input, |line| Ok(String::from(line)))
+    }
+
+    fn part1(_input: &Vec<String>) -> u64 {
+        0
+    }
+
+    fn part2(_input: &Vec<String>) -> u64 {
+        0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = include_str!("../inputs/i{day}_example.txt");
+
+    #[test]
+    #[ignore = "fill in the example answer"]
+    fn solves_example_part1() {
+        let input = Day{day}::parse(EXAMPLE.trim_end()).unwrap();
+        assert_eq!(Day{day}::part1(&input), 0);
+    }
+
+    #[test]
+    #[ignore = "fill in the example answer"]
+    fn solves_example_part2() {
+        let input = Day{day}::parse(EXAMPLE.trim_end()).unwrap();
+        assert_eq!(Day{day}::part2(&input), 0);
+    }
+}
+"#;
+
+const DAYS_ARRAY_START: &str = "pub static DAYS: [Day; ";
+const EMBEDDED_INPUTS_ARRAY_START: &str = "const EMBEDDED_INPUTS: [(u8, &str); ";
+
+// Adds an entry to the end of a fixed-size static array, bumping its declared length.
+// Returns None if the declaration isn't laid out the way the registry files are.
+fn append_to_array(source: &str, array_start: &str, entry: &str) -> Option<String> {
+    let length_start = source.find(array_start)? + array_start.len();
+    let length_end = length_start + source[length_start..].find(']')?;
+    let array_length = source[length_start..length_end].parse::<usize>().ok()?;
+    let array_end = length_end + source[length_end..].find("\n];")? + 1;
+    Some(format!(
+        "{}{}{}    {},\n{}",
+        &source[..length_start],
+        array_length + 1,
+        &source[length_end..array_end],
+        entry,
+        &source[array_end..],
+    ))
+}
+
+// Declares the module after the last existing day module and adds it to DAYS
+fn register_day(problems_mod: &str, day: u8) -> Option<String> {
+    let last_module_start = problems_mod.rfind("pub mod p")?;
+    let last_module_end = last_module_start + problems_mod[last_module_start..].find('\n')? + 1;
+    let with_module = format!(
+        "{}pub mod p{};\n{}",
+        &problems_mod[..last_module_end],
+        day,
+        &problems_mod[last_module_end..],
+    );
+    append_to_array(&with_module, DAYS_ARRAY_START, &format!("Day::new::<p{0}::Day{0}>()", day))
+}
+
+fn register_embedded_input(inputs_mod: &str, day: u8) -> Option<String> {
+    append_to_array(inputs_mod, EMBEDDED_INPUTS_ARRAY_START, &format!("({0}, include_str!(\"i{0}.txt\"))", day))
+}
+
+fn read_file(path: &Path) -> Result<String> {
+    fs::read_to_string(path).map_err(|source| Error::Io { path: path.to_path_buf(), source })
+}
+
+fn write_file(path: &Path, contents: &str) -> Result<()> {
+    fs::write(path, contents).map_err(|source| Error::Io { path: path.to_path_buf(), source })
+}
+
+// Creates the solution module, empty input and example files, and registers the day with the
+// runner and the embedded inputs. Everything is checked before anything is written, so a
+// refusal leaves the tree untouched. Returns every file created or changed.
+pub fn scaffold_day(crate_root: &Path, day: u8) -> Result<Vec<PathBuf>> {
+    if !(1..=25).contains(&day) {
+        return Err(Error::Scaffold(format!("day {} is not between 1 and 25", day)));
+    }
+    let problems_dir = crate_root.join("src").join("problems");
+    let inputs_dir = crate_root.join("src").join("inputs");
+    let solution_path = problems_dir.join(format!("p{}.rs", day));
+    let problems_mod_path = problems_dir.join("mod.rs");
+    let inputs_mod_path = inputs_dir.join("mod.rs");
+    let input_path = inputs_dir.join(format!("i{}.txt", day));
+    let example_path = inputs_dir.join(format!("i{}_example.txt", day));
+
+    for new_path in [&solution_path, &input_path, &example_path] {
+        if new_path.exists() {
+            return Err(Error::Scaffold(format!("{} already exists", new_path.display())));
+        }
+    }
+    let problems_mod = read_file(&problems_mod_path)?;
+    if problems_mod.contains(&format!("pub mod p{};", day)) {
+        return Err(Error::Scaffold(format!("day {} is already registered in {}", day, problems_mod_path.display())));
+    }
+    let updated_problems_mod = register_day(&problems_mod, day).ok_or_else(
+        || Error::Scaffold(format!("couldn't find the day modules and DAYS in {}", problems_mod_path.display()))
+    )?;
+    let updated_inputs_mod = register_embedded_input(&read_file(&inputs_mod_path)?, day).ok_or_else(
+        || Error::Scaffold(format!("couldn't find EMBEDDED_INPUTS in {}", inputs_mod_path.display()))
+    )?;
+
+    write_file(&solution_path, &SOLUTION_TEMPLATE.replace("{day}", &day.to_string()))?;
+    write_file(&input_path, "")?;
+    write_file(&example_path, "")?;
+    write_file(&problems_mod_path, &updated_problems_mod)?;
+    write_file(&inputs_mod_path, &updated_inputs_mod)?;
+    Ok(vec![solution_path, input_path, example_path, problems_mod_path, inputs_mod_path])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROBLEMS_MOD: &str = "use crate::solution::Day;\n\npub mod p1;\npub mod p2;\n\npub static DAYS: [Day; 2] = [\n    Day::new::<p1::SonarSweep>(),\n    Day::new::<p2::Dive>(),\n];\n\npub fn get_day() {}\n";
+    const INPUTS_MOD: &str = "const EMBEDDED_INPUTS: [(u8, &str); 1] = [\n    (1, include_str!(\"i1.txt\")),\n];\n";
+
+    #[test]
+    fn registers_day_module() {
+        let updated = register_day(PROBLEMS_MOD, 3).unwrap();
+        assert!(updated.contains("pub mod p2;\npub mod p3;\n\npub static DAYS: [Day; 3] = ["));
+        assert!(updated.contains("    Day::new::<p2::Dive>(),\n    Day::new::<p3::Day3>(),\n];\n\npub fn get_day"));
+        assert_eq!(register_day("no days here", 3), None);
+    }
+
+    #[test]
+    fn registers_embedded_input() {
+        let updated = register_embedded_input(INPUTS_MOD, 2).unwrap();
+        assert_eq!(
+            updated,
+            "const EMBEDDED_INPUTS: [(u8, &str); 2] = [\n    (1, include_str!(\"i1.txt\")),\n    (2, include_str!(\"i2.txt\")),\n];\n"
+        );
+    }
+
+    #[test]
+    fn scaffolds_into_a_crate_tree() {
+        let crate_root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(crate_root.join("src/problems")).unwrap();
+        fs::create_dir_all(crate_root.join("src/inputs")).unwrap();
+        fs::write(crate_root.join("src/problems/mod.rs"), PROBLEMS_MOD).unwrap();
+        fs::write(crate_root.join("src/inputs/mod.rs"), INPUTS_MOD).unwrap();
+
+        let touched_paths = scaffold_day(&crate_root, 3).unwrap();
+        assert_eq!(touched_paths.len(), 5);
+        let solution = fs::read_to_string(crate_root.join("src/problems/p3.rs")).unwrap();
+        assert!(solution.contains("pub struct Day3;"));
+        assert!(solution.contains("include_str!(\"../inputs/i3_example.txt\")"));
+        assert_eq!(fs::read_to_string(crate_root.join("src/inputs/i3.txt")).unwrap(), "");
+
+        assert!(matches!(scaffold_day(&crate_root, 3), Err(Error::Scaffold(_))));
+        assert!(matches!(scaffold_day(&crate_root, 26), Err(Error::Scaffold(_))));
+        fs::remove_dir_all(&crate_root).unwrap();
+    }
+}
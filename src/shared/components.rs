@@ -0,0 +1,94 @@
+use crate::shared::grid::Grid;
+use crate::shared::positions::Position;
+
+// One orthogonally connected region of passable cells
+pub struct Component {
+    pub label: usize,
+    pub members: Vec<Position>,
+    // Corners of the smallest rectangle holding every member, both included
+    pub top_left: Position,
+    pub bottom_right: Position,
+}
+
+impl Component {
+    pub fn size(&self) -> usize {
+        self.members.len()
+    }
+}
+
+pub struct Components {
+    // Each cell's component label, which is also its index into `components`; None if impassable
+    pub labels: Grid<Option<usize>>,
+    pub components: Vec<Component>,
+}
+
+impl Components {
+    // The `count` biggest components, biggest first
+    pub fn largest(&self, count: usize) -> Vec<&Component> {
+        let mut by_size: Vec<&Component> = self.components.iter().collect();
+        by_size.sort_by_key(|component| std::cmp::Reverse(component.size()));
+        by_size.truncate(count);
+        by_size
+    }
+}
+
+// Labels every region in one sweep: the first unlabelled passable cell in row-major order
+// starts a new component, which is filled out before the sweep moves on.
+pub fn label_components<T>(grid: &Grid<T>, passable: impl Fn(&T) -> bool) -> Components {
+    let mut labels: Grid<Option<usize>> = Grid::new(grid.num_rows(), grid.num_cols(), None);
+    let mut components: Vec<Component> = Vec::new();
+
+    for position in grid.positions() {
+        if labels[position].is_some() || !passable(&grid[position]) {
+            continue;
+        }
+        let label = components.len();
+        let mut component = Component { label, members: Vec::new(), top_left: position, bottom_right: position };
+        labels[position] = Some(label);
+        let mut fill_stack = vec![position];
+        while let Some(curr_position) = fill_stack.pop() {
+            component.top_left = Position(component.top_left.0.min(curr_position.0), component.top_left.1.min(curr_position.1));
+            component.bottom_right = Position(component.bottom_right.0.max(curr_position.0), component.bottom_right.1.max(curr_position.1));
+            component.members.push(curr_position);
+            for neighbor_pos in grid.orthogonal_neighbors(curr_position) {
+                if labels[neighbor_pos].is_none() && passable(&grid[neighbor_pos]) {
+                    labels[neighbor_pos] = Some(label);
+                    fill_stack.push(neighbor_pos);
+                }
+            }
+        }
+        components.push(component);
+    }
+
+    Components { labels, components }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn labels_separate_regions() {
+        let grid = Grid::parse_chars("##.#\n#..#\n.#..").unwrap();
+        let components = label_components(&grid, |cell| *cell == '#');
+        assert_eq!(components.components.len(), 3);
+        let sizes: Vec<usize> = components.components.iter().map(Component::size).collect();
+        assert_eq!(sizes, vec![3, 2, 1]);
+
+        assert_eq!(components.labels[Position(0, 0)], Some(0));
+        assert_eq!(components.labels[Position(1, 3)], Some(1));
+        assert_eq!(components.labels[Position(2, 1)], Some(2));
+        assert_eq!(components.labels[Position(0, 2)], None);
+
+        let right_column = &components.components[1];
+        assert_eq!((right_column.top_left, right_column.bottom_right), (Position(0, 3), Position(1, 3)));
+    }
+
+    #[test]
+    fn finds_largest_components() {
+        let grid = Grid::parse_digits("2199943210\n3987894921\n9856789892\n8767896789\n9899965678").unwrap();
+        let basins = label_components(&grid, |height| *height < 9);
+        let largest_sizes: Vec<usize> = basins.largest(3).iter().map(|basin| basin.size()).collect();
+        assert_eq!(largest_sizes, vec![14, 9, 9]);
+    }
+}
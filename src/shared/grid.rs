@@ -0,0 +1,233 @@
+use std::ops::{Index, IndexMut};
+
+use crate::error::{Error, Result};
+use crate::shared::positions::{Bounds, Position};
+
+// A rectangular grid stored row by row in one Vec. Positions are (row, column), matching
+// how the puzzle text reads top to bottom and left to right.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    num_rows: usize,
+    num_cols: usize,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(num_rows: usize, num_cols: usize, fill: T) -> Grid<T> {
+        Grid { cells: vec![fill; num_rows * num_cols], num_rows, num_cols }
+    }
+}
+
+impl<T> Grid<T> {
+    // Builds a grid from row-major cells; panics if they don't fill whole rows of num_cols
+    pub fn from_cells(num_cols: usize, cells: Vec<T>) -> Grid<T> {
+        if num_cols == 0 || !cells.len().is_multiple_of(num_cols) {
+            panic!("{} cells can't be split into rows of {}", cells.len(), num_cols);
+        }
+        Grid { num_rows: cells.len() / num_cols, num_cols, cells }
+    }
+
+    // Parses one row per line, turning each character into a cell. Errors point at the first
+    // character `parse_cell` rejects, or at the first line whose length differs from the first.
+    pub fn parse_with(
+        text: &str, expected: &str, parse_cell: impl Fn(char) -> Option<T>
+    ) -> Result<Grid<T>> {
+        let text_lines: Vec<&str> = text.split("\n").collect();
+        let num_cols = text_lines[0].chars().count();
+        if num_cols == 0 {
+            return Err(Error::parse(text_lines[0], "expected a row of cells"));
+        }
+        let mut cells = Vec::with_capacity(num_cols * text_lines.len());
+        for text_line in text_lines.iter() {
+            let mut line_length = 0;
+            for (char_index, character) in text_line.char_indices() {
+                match parse_cell(character) {
+                    Some(cell) => cells.push(cell),
+                    None => return Err(Error::parse(&text_line[char_index..char_index + character.len_utf8()], expected)),
+                }
+                line_length += 1;
+            }
+            if line_length != num_cols {
+                return Err(Error::parse(text_line, format!("expected {} cells", num_cols)));
+            }
+        }
+        Ok(Grid::from_cells(num_cols, cells))
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    pub fn num_cols(&self) -> usize {
+        self.num_cols
+    }
+
+    pub fn bounds(&self) -> Bounds {
+        Bounds { num_rows: self.num_rows, num_cols: self.num_cols }
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        self.bounds().contains(position)
+    }
+
+    pub fn orthogonal_neighbors(&self, position: Position) -> impl Iterator<Item = Position> {
+        position.orthogonal_neighbors(self.bounds())
+    }
+
+    pub fn all_neighbors(&self, position: Position) -> impl Iterator<Item = Position> {
+        position.all_neighbors(self.bounds())
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        match self.contains(position) {
+            true => Some(&self.cells[position.0 * self.num_cols + position.1]),
+            false => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        match self.contains(position) {
+            true => Some(&mut self.cells[position.0 * self.num_cols + position.1]),
+            false => None,
+        }
+    }
+
+    pub fn row(&self, row_index: usize) -> &[T] {
+        &self.cells[row_index * self.num_cols..(row_index + 1) * self.num_cols]
+    }
+
+    // Goes by row index rather than chunking the cells, so a grid zero columns wide still has
+    // its (empty) rows
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.num_rows).map(move |row_index| self.row(row_index))
+    }
+
+    pub fn column(&self, col_index: usize) -> impl Iterator<Item = &T> {
+        if col_index >= self.num_cols {
+            panic!("column {} is outside a grid {} wide", col_index, self.num_cols);
+        }
+        self.cells[col_index..].iter().step_by(self.num_cols)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.num_cols).map(move |col_index| self.column(col_index))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    // Every position in row-major order
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let num_cols = self.num_cols;
+        (0..self.num_rows * num_cols).map(move |cell_index| Position(cell_index / num_cols, cell_index % num_cols))
+    }
+
+    pub fn enumerate(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn map<U>(&self, map_cell: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid { cells: self.cells.iter().map(map_cell).collect(), num_rows: self.num_rows, num_cols: self.num_cols }
+    }
+}
+
+impl Grid<u8> {
+    pub fn parse_digits(text: &str) -> Result<Grid<u8>> {
+        Grid::parse_with(text, "expected a digit", |character| character.to_digit(10).map(|digit| digit as u8))
+    }
+}
+
+impl Grid<char> {
+    pub fn parse_chars(text: &str) -> Result<Grid<char>> {
+        Grid::parse_with(text, "expected a character", Some)
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        match self.get(position) {
+            Some(cell) => cell,
+            None => panic!("({}, {}) is outside a {}x{} grid", position.0, position.1, self.num_rows, self.num_cols),
+        }
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        let (num_rows, num_cols) = (self.num_rows, self.num_cols);
+        match self.get_mut(position) {
+            Some(cell) => cell,
+            None => panic!("({}, {}) is outside a {}x{} grid", position.0, position.1, num_rows, num_cols),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_digits_row_major() {
+        let grid = Grid::parse_digits("123\n456").unwrap();
+        assert_eq!((grid.num_rows(), grid.num_cols()), (2, 3));
+        assert_eq!(grid[Position(0, 2)], 3);
+        assert_eq!(grid[Position(1, 0)], 4);
+        assert_eq!(grid.get(Position(2, 0)), None);
+        assert_eq!(grid.get(Position(0, 3)), None);
+    }
+
+    #[test]
+    fn iterates_rows_and_columns() {
+        let grid = Grid::parse_chars("ab\ncd\nef").unwrap();
+        let rows: Vec<String> = grid.rows().map(|row| row.iter().collect()).collect();
+        assert_eq!(rows, vec!["ab", "cd", "ef"]);
+        let columns: Vec<String> = grid.columns().map(|column| column.collect()).collect();
+        assert_eq!(columns, vec!["ace", "bdf"]);
+        assert_eq!(grid.row(1), &['c', 'd']);
+        assert_eq!(grid.positions().last(), Some(Position(2, 1)));
+        assert_eq!(grid.orthogonal_neighbors(Position(2, 1)).count(), 2);
+    }
+
+    #[test]
+    fn iterates_empty_rows() {
+        let grid = Grid::new(3, 0, '.');
+        assert_eq!(grid.rows().map(|row| row.len()).collect::<Vec<usize>>(), vec![0, 0, 0]);
+        assert_eq!(grid.columns().count(), 0);
+        assert_eq!(grid.positions().count(), 0);
+    }
+
+    #[test]
+    fn indexes_mutably_and_maps() {
+        let mut grid = Grid::new(2, 2, 0u8);
+        grid[Position(1, 0)] = 7;
+        *grid.get_mut(Position(0, 1)).unwrap() += 1;
+        assert_eq!(grid.iter().copied().collect::<Vec<u8>>(), vec![0, 1, 7, 0]);
+        let doubled = grid.map(|cell| *cell as u32 * 2);
+        assert_eq!(doubled[Position(1, 0)], 14);
+    }
+
+    #[test]
+    fn rejects_bad_digits_and_ragged_rows() {
+        let raw_input = "12\n3x";
+        let err = Grid::parse_digits(raw_input).unwrap_err().locate(raw_input);
+        assert_eq!(err.to_string(), "<input>:2:2: expected a digit, found \"x\"");
+
+        let raw_input = "12\n345";
+        let err = Grid::parse_digits(raw_input).unwrap_err().locate(raw_input);
+        assert_eq!(err.to_string(), "<input>:2:1: expected 2 cells, found \"345\"");
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_indexing_outside() {
+        let grid = Grid::new(2, 2, 0u8);
+        let _ = grid[Position(2, 0)];
+    }
+}
@@ -1 +1,7 @@
+pub mod components;
+pub mod grid;
+pub mod parse;
 pub mod positions;
+pub mod render;
+pub mod search;
+pub mod sparse;
@@ -0,0 +1,112 @@
+use std::fmt;
+use std::str::FromStr;
+
+use regex::Regex;
+
+pub use crate::error::parse_token;
+use crate::error::{Error, Result};
+use crate::shared::grid::Grid;
+
+// Every helper hands back slices of the text it was given and errors on those same slices, so
+// Error::locate can still find the line and column in the raw puzzle input.
+
+pub fn lines(text: &str) -> impl Iterator<Item = &str> {
+    text.split('\n')
+}
+
+// Groups of lines separated by a blank line
+pub fn blocks(text: &str) -> impl Iterator<Item = &str> {
+    text.split("\n\n")
+}
+
+pub fn map_lines<'a, T>(text: &'a str, parse_line: impl FnMut(&'a str) -> Result<T>) -> Result<Vec<T>> {
+    lines(text).map(parse_line).collect()
+}
+
+// One value per line, e.g. a column of depths
+pub fn parse_lines<T: FromStr>(text: &str) -> Result<Vec<T>> where T::Err: fmt::Display {
+    map_lines(text, parse_token::<T>)
+}
+
+pub fn separated<T: FromStr>(text: &str, separator: &str) -> Result<Vec<T>> where T::Err: fmt::Display {
+    text.split(separator).map(parse_token::<T>).collect()
+}
+
+pub fn comma_separated<T: FromStr>(text: &str) -> Result<Vec<T>> where T::Err: fmt::Display {
+    separated(text, ",")
+}
+
+// Tokens split on any run of whitespace, ignoring leading and trailing space
+pub fn whitespace_separated<T: FromStr>(text: &str) -> Result<Vec<T>> where T::Err: fmt::Display {
+    text.split_whitespace().map(parse_token::<T>).collect()
+}
+
+pub fn digit_grid(text: &str) -> Result<Grid<u8>> {
+    Grid::parse_digits(text)
+}
+
+// Splits at the first separator, as in `key-value`, `name value` or `a | b`
+pub fn pair<'a>(text: &'a str, separator: &str) -> Result<(&'a str, &'a str)> {
+    text.split_once(separator)
+        .ok_or_else(|| Error::parse(text, format!("expected two parts separated by {:?}", separator)))
+}
+
+pub fn parse_pair<A: FromStr, B: FromStr>(text: &str, separator: &str) -> Result<(A, B)>
+where A::Err: fmt::Display, B::Err: fmt::Display {
+    let (first, second) = pair(text, separator)?;
+    Ok((parse_token::<A>(first)?, parse_token::<B>(second)?))
+}
+
+// `a -> b`, as in line segments or insertion rules
+pub fn arrow_pair(text: &str) -> Result<(&str, &str)> {
+    pair(text, " -> ")
+}
+
+// Every capture group of a regex that must match, as slices of the text. Keep the Regex in a
+// static LazyLock so it is compiled once rather than on every call.
+pub fn captures<'a>(regex: &Regex, text: &'a str, expected: &str) -> Result<Vec<&'a str>> {
+    match regex.captures(text) {
+        Some(capture) => Ok(capture.iter().skip(1).map(|group| group.map_or("", |group| group.as_str())).collect()),
+        None => Err(Error::parse(text, expected)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::LazyLock;
+
+    #[test]
+    fn splits_lines_blocks_and_lists() {
+        assert_eq!(parse_lines::<u32>("199\n200\n208").unwrap(), vec![199, 200, 208]);
+        assert_eq!(comma_separated::<i32>("16,-1,2").unwrap(), vec![16, -1, 2]);
+        assert_eq!(whitespace_separated::<u8>(" 8  2 23").unwrap(), vec![8, 2, 23]);
+        assert_eq!(blocks("7,4\n\n22 13\n 8  2").collect::<Vec<&str>>(), vec!["7,4", "22 13\n 8  2"]);
+        assert_eq!(digit_grid("12\n34").unwrap().num_rows(), 2);
+    }
+
+    #[test]
+    fn splits_pairs() {
+        assert_eq!(pair("start-A", "-").unwrap(), ("start", "A"));
+        assert_eq!(arrow_pair("0,9 -> 5,9").unwrap(), ("0,9", "5,9"));
+        assert_eq!(parse_pair::<String, u32>("forward 5", " ").unwrap(), (String::from("forward"), 5));
+    }
+
+    #[test]
+    fn locates_errors_in_raw_input() {
+        let raw_input = "1,2\n3,x";
+        let err = map_lines(raw_input, |line| parse_pair::<u8, u8>(line, ",")).unwrap_err().locate(raw_input);
+        assert_eq!(err.to_string(), "<input>:2:3: invalid digit found in string, found \"x\"");
+
+        let raw_input = "a-b\nc";
+        let err = map_lines(raw_input, |line| pair(line, "-")).unwrap_err().locate(raw_input);
+        assert_eq!(err.to_string(), "<input>:2:1: expected two parts separated by \"-\", found \"c\"");
+    }
+
+    #[test]
+    fn captures_regex_groups() {
+        static POINT_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^(\d+),(\d+)$").unwrap());
+        assert_eq!(captures(&POINT_REGEX, "3,14", "expected x,y").unwrap(), vec!["3", "14"]);
+        assert!(captures(&POINT_REGEX, "3;14", "expected x,y").is_err());
+    }
+}
@@ -1,31 +1,421 @@
-#[derive(Copy, Clone, Hash)]
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+use crate::error::{Error, Result};
+
+// Position is a (row, column) index into a grid. Point below is for puzzles that live on an
+// open plane or in space, where coordinates can go negative and there may be more than two.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
 pub struct Position(pub usize, pub usize);
 
-impl PartialEq for Position {
-    fn eq(&self, other: &Self) -> bool {
-        self.0 == other.0 && self.1 == other.1
-    }
+// A single step as (rows, columns), so north is up the screen and south is down it
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Direction(pub isize, pub isize);
+
+// The extent of a grid in rows and columns; a Position is inside when both parts are below it
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Bounds {
+    pub num_rows: usize,
+    pub num_cols: usize,
 }
-impl Eq for Position {}
 
-pub struct Direction(isize, isize);
+impl Bounds {
+    pub fn contains(&self, position: Position) -> bool {
+        position.0 < self.num_rows && position.1 < self.num_cols
+    }
+}
 
 impl Position {
-    pub fn neighbor(
-        &self, direction: &Direction, y_bound: usize, x_bound: usize
-    ) -> Option<Position> {
-        let new_y: isize = self.0 as isize + direction.0;
-        let new_x: isize = self.1 as isize + direction.1;
-        if new_y >= 0 && new_y < y_bound as isize && new_x >= 0 && new_x < x_bound as isize {
-            Some(Position(new_y as usize, new_x as usize))
-        } else {
-            None
+    // Steps one cell in the given direction, or None if that would go below zero
+    pub fn offset(&self, direction: &Direction) -> Option<Position> {
+        Some(Position(self.0.checked_add_signed(direction.0)?, self.1.checked_add_signed(direction.1)?))
+    }
+
+    fn bounded_neighbors(
+        self, directions: &'static [Direction], bounds: Bounds
+    ) -> impl Iterator<Item = Position> {
+        directions.iter()
+            .filter_map(move |direction| self.offset(direction))
+            .filter(move |neighbor_pos| bounds.contains(*neighbor_pos))
+    }
+
+    // Steps off one edge come back in on the opposite one. On a grid only one or two cells
+    // across, the same neighbor (or the position itself) can come up more than once, and an
+    // empty grid has no neighbors at all.
+    fn wrapping_neighbors(
+        self, directions: &'static [Direction], bounds: Bounds
+    ) -> impl Iterator<Item = Position> {
+        let is_empty = bounds.num_rows == 0 || bounds.num_cols == 0;
+        directions.iter().filter(move |_| !is_empty).map(move |direction| Position(
+            (self.0 as isize + direction.0).rem_euclid(bounds.num_rows as isize) as usize,
+            (self.1 as isize + direction.1).rem_euclid(bounds.num_cols as isize) as usize,
+        ))
+    }
+
+    pub fn orthogonal_neighbors(self, bounds: Bounds) -> impl Iterator<Item = Position> {
+        self.bounded_neighbors(&NON_DIAG_DIRECTIONS, bounds)
+    }
+
+    pub fn all_neighbors(self, bounds: Bounds) -> impl Iterator<Item = Position> {
+        self.bounded_neighbors(&DIRECTIONS, bounds)
+    }
+
+    pub fn wrapping_orthogonal_neighbors(self, bounds: Bounds) -> impl Iterator<Item = Position> {
+        self.wrapping_neighbors(&NON_DIAG_DIRECTIONS, bounds)
+    }
+
+    pub fn wrapping_all_neighbors(self, bounds: Bounds) -> impl Iterator<Item = Position> {
+        self.wrapping_neighbors(&DIRECTIONS, bounds)
+    }
+}
+
+impl Direction {
+    pub const NORTH: Direction = Direction(-1, 0);
+    pub const SOUTH: Direction = Direction(1, 0);
+    pub const WEST: Direction = Direction(0, -1);
+    pub const EAST: Direction = Direction(0, 1);
+    pub const NORTH_WEST: Direction = Direction(-1, -1);
+    pub const SOUTH_WEST: Direction = Direction(1, -1);
+    pub const NORTH_EAST: Direction = Direction(-1, 1);
+    pub const SOUTH_EAST: Direction = Direction(1, 1);
+
+    // Quarter turns as seen on screen; diagonals turn too, e.g. north-east goes right to south-east
+    pub fn turn_right(&self) -> Direction {
+        Direction(self.1, -self.0)
+    }
+
+    pub fn turn_left(&self) -> Direction {
+        Direction(-self.1, self.0)
+    }
+
+    pub fn turn_around(&self) -> Direction {
+        Direction(-self.0, -self.1)
+    }
+
+    // Reads arrows (^v<>), UDLR, or the compass letters N, S, E and W. Diagonals like NE take
+    // two characters, so only TryFrom<&str> reads those.
+    pub fn from_char(direction_char: char) -> Option<Direction> {
+        match direction_char {
+            '^' | 'U' | 'N' => Some(Direction::NORTH),
+            'v' | 'D' | 'S' => Some(Direction::SOUTH),
+            '<' | 'L' | 'W' => Some(Direction::WEST),
+            '>' | 'R' | 'E' => Some(Direction::EAST),
+            _ => None,
         }
     }
 }
 
-pub const NON_DIAG_DIRECTIONS: [Direction; 4] = [Direction(-1, 0), Direction(1, 0), Direction(0, -1), Direction(0, 1)];
+impl TryFrom<&str> for Direction {
+    type Error = Error;
+
+    fn try_from(direction_str: &str) -> Result<Self> {
+        let mut direction_chars = direction_str.chars();
+        let direction = match (direction_chars.next(), direction_chars.next(), direction_chars.next()) {
+            (Some(direction_char), None, None) => Direction::from_char(direction_char),
+            (Some('N'), Some('W'), None) => Some(Direction::NORTH_WEST),
+            (Some('S'), Some('W'), None) => Some(Direction::SOUTH_WEST),
+            (Some('N'), Some('E'), None) => Some(Direction::NORTH_EAST),
+            (Some('S'), Some('E'), None) => Some(Direction::SOUTH_EAST),
+            _ => None,
+        };
+        direction.ok_or_else(|| Error::parse(direction_str, "expected a direction like ^v<>, UDLR or N/NE/E/SE/S/SW/W/NW"))
+    }
+}
+
+// x is the column step and y the row step, matching Point2's screen orientation
+impl From<Direction> for Point2 {
+    fn from(direction: Direction) -> Point2 {
+        Point2::new(direction.1 as i64, direction.0 as i64)
+    }
+}
+
+pub const NON_DIAG_DIRECTIONS: [Direction; 4] = [Direction::NORTH, Direction::SOUTH, Direction::WEST, Direction::EAST];
 pub const DIRECTIONS: [Direction; 8] = [
-    Direction(-1, 0), Direction(1, 0), Direction(0, -1), Direction(0, 1),
-    Direction(-1, -1), Direction(1, -1), Direction(-1, 1), Direction(1, 1),
+    Direction::NORTH, Direction::SOUTH, Direction::WEST, Direction::EAST,
+    Direction::NORTH_WEST, Direction::SOUTH_WEST, Direction::NORTH_EAST, Direction::SOUTH_EAST,
 ];
+
+// A point or offset with N signed components. The 2D variant is (x, y) with y growing
+// downwards, like the puzzle text, so rotations are described as they look on screen.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<const N: usize>(pub [i64; N]);
+
+pub type Point2 = Point<2>;
+pub type Point3 = Point<3>;
+pub type Point4 = Point<4>;
+
+impl<const N: usize> Point<N> {
+    pub const ORIGIN: Point<N> = Point([0; N]);
+
+    pub fn manhattan_distance(&self, other: &Point<N>) -> u64 {
+        self.0.iter().zip(other.0.iter()).map(|(first, second)| first.abs_diff(*second)).sum()
+    }
+
+    pub fn chebyshev_distance(&self, other: &Point<N>) -> u64 {
+        self.0.iter().zip(other.0.iter()).map(|(first, second)| first.abs_diff(*second)).max().unwrap_or(0)
+    }
+
+    // Each component clamped to -1, 0 or 1: the single step that heads from the origin towards self
+    pub fn signum(&self) -> Point<N> {
+        Point(self.0.map(i64::signum))
+    }
+}
+
+impl Point<2> {
+    pub const fn new(x: i64, y: i64) -> Point2 {
+        Point([x, y])
+    }
+
+    pub fn x(&self) -> i64 {
+        self.0[0]
+    }
+
+    pub fn y(&self) -> i64 {
+        self.0[1]
+    }
+
+    // Quarter turns about the origin
+    pub fn rotate_right(&self) -> Point2 {
+        Point2::new(-self.y(), self.x())
+    }
+
+    pub fn rotate_left(&self) -> Point2 {
+        Point2::new(self.y(), -self.x())
+    }
+
+    pub fn rotate_around(&self) -> Point2 {
+        -*self
+    }
+
+    // Neighbors on the infinite plane, so every step is kept
+    pub fn orthogonal_neighbors(self) -> impl Iterator<Item = Point2> {
+        NON_DIAG_DIRECTIONS.iter().map(move |direction| self + Point2::from(*direction))
+    }
+
+    pub fn all_neighbors(self) -> impl Iterator<Item = Point2> {
+        DIRECTIONS.iter().map(move |direction| self + Point2::from(*direction))
+    }
+}
+
+impl Point<3> {
+    pub const fn new(x: i64, y: i64, z: i64) -> Point3 {
+        Point([x, y, z])
+    }
+
+    pub fn x(&self) -> i64 {
+        self.0[0]
+    }
+
+    pub fn y(&self) -> i64 {
+        self.0[1]
+    }
+
+    pub fn z(&self) -> i64 {
+        self.0[2]
+    }
+
+    // Quarter turns about each axis, counter-clockwise when looking down the axis at the origin
+    pub fn rotate_x(&self) -> Point3 {
+        Point3::new(self.x(), -self.z(), self.y())
+    }
+
+    pub fn rotate_y(&self) -> Point3 {
+        Point3::new(self.z(), self.y(), -self.x())
+    }
+
+    pub fn rotate_z(&self) -> Point3 {
+        Point3::new(-self.y(), self.x(), self.z())
+    }
+
+    // The 24 orientations reachable by quarter turns: each of the six directions the x axis can
+    // face, combined with the four turns about it. The identity comes first.
+    pub fn rotations(&self) -> [Point3; 24] {
+        let facings = [
+            *self,
+            self.rotate_z(),
+            self.rotate_z().rotate_z(),
+            self.rotate_z().rotate_z().rotate_z(),
+            self.rotate_y(),
+            self.rotate_y().rotate_y().rotate_y(),
+        ];
+        let mut rotations = [*self; 24];
+        for (facing_index, facing) in facings.iter().enumerate() {
+            let mut rotated = *facing;
+            for turn_index in 0..4 {
+                rotations[facing_index * 4 + turn_index] = rotated;
+                rotated = rotated.rotate_x();
+            }
+        }
+        rotations
+    }
+}
+
+impl Point<4> {
+    pub const fn new(x: i64, y: i64, z: i64, w: i64) -> Point4 {
+        Point([x, y, z, w])
+    }
+
+    pub fn x(&self) -> i64 {
+        self.0[0]
+    }
+
+    pub fn y(&self) -> i64 {
+        self.0[1]
+    }
+
+    pub fn z(&self) -> i64 {
+        self.0[2]
+    }
+
+    pub fn w(&self) -> i64 {
+        self.0[3]
+    }
+}
+
+impl<const N: usize> Default for Point<N> {
+    fn default() -> Point<N> {
+        Point::ORIGIN
+    }
+}
+
+impl<const N: usize> Add for Point<N> {
+    type Output = Point<N>;
+
+    fn add(self, other: Point<N>) -> Point<N> {
+        Point(std::array::from_fn(|axis| self.0[axis] + other.0[axis]))
+    }
+}
+
+impl<const N: usize> Sub for Point<N> {
+    type Output = Point<N>;
+
+    fn sub(self, other: Point<N>) -> Point<N> {
+        Point(std::array::from_fn(|axis| self.0[axis] - other.0[axis]))
+    }
+}
+
+impl<const N: usize> Mul<i64> for Point<N> {
+    type Output = Point<N>;
+
+    fn mul(self, scale: i64) -> Point<N> {
+        Point(self.0.map(|component| component * scale))
+    }
+}
+
+impl<const N: usize> Neg for Point<N> {
+    type Output = Point<N>;
+
+    fn neg(self) -> Point<N> {
+        Point(self.0.map(|component| -component))
+    }
+}
+
+impl<const N: usize> AddAssign for Point<N> {
+    fn add_assign(&mut self, other: Point<N>) {
+        *self = *self + other;
+    }
+}
+
+impl<const N: usize> SubAssign for Point<N> {
+    fn sub_assign(&mut self, other: Point<N>) {
+        *self = *self - other;
+    }
+}
+
+// Column becomes x and row becomes y, so grid positions and 2D points line up on screen
+impl From<Position> for Point2 {
+    fn from(position: Position) -> Point2 {
+        Point2::new(position.1 as i64, position.0 as i64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOUNDS: Bounds = Bounds { num_rows: 3, num_cols: 4 };
+
+    #[test]
+    fn bounded_neighbors_stay_inside() {
+        let corner_neighbors: Vec<Position> = Position(0, 0).orthogonal_neighbors(BOUNDS).collect();
+        assert_eq!(corner_neighbors, vec![Position(1, 0), Position(0, 1)]);
+        assert_eq!(Position(2, 3).all_neighbors(BOUNDS).count(), 3);
+        assert_eq!(Position(1, 1).all_neighbors(BOUNDS).count(), 8);
+    }
+
+    #[test]
+    fn wrapping_neighbors_cross_edges() {
+        let corner_neighbors: Vec<Position> = Position(0, 0).wrapping_orthogonal_neighbors(BOUNDS).collect();
+        assert_eq!(corner_neighbors, vec![Position(2, 0), Position(1, 0), Position(0, 3), Position(0, 1)]);
+        assert!(Position(2, 3).wrapping_all_neighbors(BOUNDS).any(|neighbor_pos| neighbor_pos == Position(0, 0)));
+        let empty_bounds = Bounds { num_rows: 0, num_cols: 4 };
+        assert_eq!(Position(0, 0).wrapping_all_neighbors(empty_bounds).count(), 0);
+    }
+
+    #[test]
+    fn plane_neighbors_go_negative() {
+        let origin_neighbors: Vec<Point2> = Point2::ORIGIN.orthogonal_neighbors().collect();
+        assert_eq!(origin_neighbors, vec![Point2::new(0, -1), Point2::new(0, 1), Point2::new(-1, 0), Point2::new(1, 0)]);
+        assert_eq!(Point2::ORIGIN.all_neighbors().count(), 8);
+        assert!(Point2::ORIGIN.all_neighbors().any(|neighbor| neighbor == Point2::new(-1, -1)));
+    }
+
+    #[test]
+    fn points_do_arithmetic() {
+        let first = Point2::new(3, -4);
+        let second = Point2::new(-1, 2);
+        assert_eq!(first + second, Point2::new(2, -2));
+        assert_eq!(first - second, Point2::new(4, -6));
+        assert_eq!(first * 3, Point2::new(9, -12));
+        assert_eq!((first - second).signum(), Point2::new(1, -1));
+        assert_eq!(Point3::new(1, 2, 3) + Point3::ORIGIN, Point3::new(1, 2, 3));
+        assert_eq!(Point2::from(Position(5, 7)), Point2::new(7, 5));
+    }
+
+    #[test]
+    fn points_measure_distance() {
+        let first = Point4::new(1, -2, 3, 0);
+        let second = Point4::new(-1, 2, 3, 1);
+        assert_eq!(first.manhattan_distance(&second), 7);
+        assert_eq!(first.chebyshev_distance(&second), 4);
+    }
+
+    #[test]
+    fn points_rotate() {
+        let east = Point2::new(1, 0);
+        assert_eq!(east.rotate_right(), Point2::new(0, 1));
+        assert_eq!(east.rotate_left(), Point2::new(0, -1));
+        assert_eq!(east.rotate_around(), Point2::new(-1, 0));
+        assert_eq!(east.rotate_right().rotate_left(), east);
+
+        let point = Point3::new(1, 2, 3);
+        assert_eq!(point.rotate_z(), Point3::new(-2, 1, 3));
+        let rotations = point.rotations();
+        assert_eq!(rotations[0], point);
+        let distinct: std::collections::HashSet<Point3> = rotations.iter().copied().collect();
+        assert_eq!(distinct.len(), 24);
+        assert!(rotations.iter().all(|rotated| rotated.manhattan_distance(&Point3::ORIGIN) == 6));
+    }
+
+    #[test]
+    fn directions_turn() {
+        assert_eq!(Direction::NORTH.turn_right(), Direction::EAST);
+        assert_eq!(Direction::EAST.turn_right(), Direction::SOUTH);
+        assert_eq!(Direction::NORTH.turn_left(), Direction::WEST);
+        assert_eq!(Direction::NORTH_EAST.turn_right(), Direction::SOUTH_EAST);
+        assert_eq!(Direction::SOUTH_WEST.turn_around(), Direction::NORTH_EAST);
+        assert_eq!(Point2::from(Direction::EAST), Point2::new(1, 0));
+        assert_eq!(Point2::from(Direction::EAST.turn_right()), Point2::new(1, 0).rotate_right());
+    }
+
+    #[test]
+    fn parses_directions() {
+        let arrows: Vec<Direction> = "^v<>".chars().filter_map(Direction::from_char).collect();
+        let letters: Vec<Direction> = "UDLR".chars().filter_map(Direction::from_char).collect();
+        assert_eq!(arrows, letters);
+        assert_eq!(arrows, NON_DIAG_DIRECTIONS.to_vec());
+        assert_eq!(Direction::try_from("SW").unwrap(), Direction::SOUTH_WEST);
+        assert_eq!(Position(2, 2).offset(&Direction::try_from(">").unwrap()), Some(Position(2, 3)));
+        assert!(Direction::try_from("X").is_err());
+        assert!(Direction::try_from("NEE").is_err());
+    }
+}
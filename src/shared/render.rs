@@ -0,0 +1,202 @@
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+
+use crate::error::{Error, Result};
+use crate::shared::grid::Grid;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+// One line per grid row, one character per cell
+pub fn render_text<T>(grid: &Grid<T>, cell_char: impl Fn(&T) -> char) -> String {
+    let mut rendered = String::with_capacity((grid.num_cols() + 1) * grid.num_rows());
+    for row in grid.rows() {
+        rendered.extend(row.iter().map(&cell_char));
+        rendered.push('\n');
+    }
+    rendered
+}
+
+// Like render_text, but cells given a color are wrapped in 24-bit ANSI foreground escapes
+pub fn render_ansi<T>(
+    grid: &Grid<T>, cell_char: impl Fn(&T) -> char, cell_color: impl Fn(&T) -> Option<Rgb>
+) -> String {
+    let mut rendered = String::new();
+    for row in grid.rows() {
+        for cell in row.iter() {
+            match cell_color(cell) {
+                Some(Rgb(red, green, blue)) => {
+                    rendered += &format!("\x1b[38;2;{};{};{}m{}\x1b[0m", red, green, blue, cell_char(cell));
+                },
+                None => rendered.push(cell_char(cell)),
+            }
+        }
+        rendered.push('\n');
+    }
+    rendered
+}
+
+// Expands every cell into a scale x scale block and returns the rows of RGB bytes
+fn pixel_rows<T>(grid: &Grid<T>, cell_color: impl Fn(&T) -> Rgb, scale: usize) -> Vec<Vec<u8>> {
+    let mut pixel_rows = Vec::with_capacity(grid.num_rows() * scale);
+    for row in grid.rows() {
+        let mut pixel_row = Vec::with_capacity(grid.num_cols() * scale * 3);
+        for cell in row.iter() {
+            let Rgb(red, green, blue) = cell_color(cell);
+            for _ in 0..scale {
+                pixel_row.extend([red, green, blue]);
+            }
+        }
+        for _ in 0..scale {
+            pixel_rows.push(pixel_row.clone());
+        }
+    }
+    pixel_rows
+}
+
+// Binary PPM (P6), which most image viewers open and is trivial to write
+pub fn write_ppm<T>(
+    grid: &Grid<T>, cell_color: impl Fn(&T) -> Rgb, scale: usize, writer: &mut impl Write
+) -> io::Result<()> {
+    write!(writer, "P6\n{} {}\n255\n", grid.num_cols() * scale, grid.num_rows() * scale)?;
+    for pixel_row in pixel_rows(grid, cell_color, scale) {
+        writer.write_all(&pixel_row)?;
+    }
+    Ok(())
+}
+
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = 0xffff_ffffu32;
+    for byte in bytes.iter() {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = match crc & 1 {
+                1 => (crc >> 1) ^ 0xedb8_8320,
+                _ => crc >> 1,
+            };
+        }
+    }
+    !crc
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (mut low_sum, mut high_sum) = (1u32, 0u32);
+    for byte in bytes.iter() {
+        low_sum = (low_sum + *byte as u32) % 65521;
+        high_sum = (high_sum + low_sum) % 65521;
+    }
+    (high_sum << 16) | low_sum
+}
+
+fn write_png_chunk(writer: &mut impl Write, chunk_type: &[u8; 4], chunk_data: &[u8]) -> io::Result<()> {
+    let mut crc_input = chunk_type.to_vec();
+    crc_input.extend_from_slice(chunk_data);
+    writer.write_all(&(chunk_data.len() as u32).to_be_bytes())?;
+    writer.write_all(&crc_input)?;
+    writer.write_all(&crc32(&crc_input).to_be_bytes())
+}
+
+// 8-bit RGB PNG. The pixel data goes into uncompressed deflate blocks, which keeps this free of
+// a compression dependency at the cost of file size.
+pub fn write_png<T>(
+    grid: &Grid<T>, cell_color: impl Fn(&T) -> Rgb, scale: usize, writer: &mut impl Write
+) -> io::Result<()> {
+    const MAX_STORED_BLOCK: usize = 65535;
+    let (width, height) = ((grid.num_cols() * scale) as u32, (grid.num_rows() * scale) as u32);
+
+    let mut scanlines = Vec::new();
+    for pixel_row in pixel_rows(grid, cell_color, scale) {
+        // Each scanline starts with its filter type, and 0 means unfiltered
+        scanlines.push(0);
+        scanlines.extend(pixel_row);
+    }
+
+    let mut zlib_stream = vec![0x78, 0x01];
+    let mut blocks = scanlines.chunks(MAX_STORED_BLOCK).peekable();
+    if blocks.peek().is_none() {
+        zlib_stream.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let is_final_block = blocks.peek().is_none();
+        zlib_stream.push(is_final_block as u8);
+        zlib_stream.extend((block.len() as u16).to_le_bytes());
+        zlib_stream.extend((!(block.len() as u16)).to_le_bytes());
+        zlib_stream.extend_from_slice(block);
+    }
+    zlib_stream.extend(adler32(&scanlines).to_be_bytes());
+
+    let mut header = Vec::with_capacity(13);
+    header.extend(width.to_be_bytes());
+    header.extend(height.to_be_bytes());
+    // Bit depth 8, color type 2 (RGB), then default compression, filtering and no interlacing
+    header.extend([8, 2, 0, 0, 0]);
+
+    writer.write_all(&[0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n'])?;
+    write_png_chunk(writer, b"IHDR", &header)?;
+    write_png_chunk(writer, b"IDAT", &zlib_stream)?;
+    write_png_chunk(writer, b"IEND", &[])
+}
+
+// Writes a PNG or PPM image depending on the file extension
+pub fn save_image<T>(grid: &Grid<T>, path: &Path, cell_color: impl Fn(&T) -> Rgb, scale: usize) -> Result<()> {
+    let io_error = |source: io::Error| Error::Io { path: path.to_path_buf(), source };
+    let mut writer = BufWriter::new(File::create(path).map_err(io_error)?);
+    let written = match path.extension().and_then(|extension| extension.to_str()) {
+        Some("ppm") => write_ppm(grid, cell_color, scale, &mut writer),
+        Some("png") => write_png(grid, cell_color, scale, &mut writer),
+        _ => Err(io::Error::new(io::ErrorKind::InvalidInput, "image files must end in .png or .ppm")),
+    };
+    written.and_then(|_| writer.flush()).map_err(io_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn checkerboard() -> Grid<bool> {
+        Grid::from_cells(3, vec![true, false, true, false, true, false])
+    }
+
+    fn cell_color(is_set: &bool) -> Rgb {
+        match is_set {
+            true => Rgb(255, 255, 255),
+            false => Rgb(0, 0, 0),
+        }
+    }
+
+    #[test]
+    fn renders_text_and_ansi() {
+        let grid = checkerboard();
+        assert_eq!(render_text(&grid, |is_set| if *is_set { '#' } else { '.' }), "#.#\n.#.\n");
+        let rendered = render_ansi(&grid, |is_set| if *is_set { '#' } else { '.' }, |is_set| is_set.then_some(Rgb(255, 0, 0)));
+        assert!(rendered.starts_with("\x1b[38;2;255;0;0m#\x1b[0m.\x1b[38;2;255;0;0m#\x1b[0m\n."));
+    }
+
+    #[test]
+    fn writes_scaled_ppm() {
+        let mut ppm_bytes = Vec::new();
+        write_ppm(&checkerboard(), cell_color, 2, &mut ppm_bytes).unwrap();
+        let header = b"P6\n6 4\n255\n";
+        assert_eq!(&ppm_bytes[..header.len()], header);
+        assert_eq!(ppm_bytes.len(), header.len() + 6 * 4 * 3);
+        assert_eq!(&ppm_bytes[header.len()..header.len() + 9], &[255, 255, 255, 255, 255, 255, 0, 0, 0]);
+    }
+
+    #[test]
+    fn writes_png_chunks() {
+        let mut png_bytes = Vec::new();
+        write_png(&checkerboard(), cell_color, 1, &mut png_bytes).unwrap();
+        assert_eq!(&png_bytes[..8], &[0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1a, b'\n']);
+        assert_eq!(&png_bytes[12..16], b"IHDR");
+        assert_eq!(&png_bytes[16..24], &[0, 0, 0, 3, 0, 0, 0, 2]);
+        // Every PNG ends with the same empty IEND chunk and its fixed checksum
+        assert_eq!(&png_bytes[png_bytes.len() - 12..], &[0, 0, 0, 0, b'I', b'E', b'N', b'D', 0xae, 0x42, 0x60, 0x82]);
+    }
+
+    #[test]
+    fn checksums_match_reference_values() {
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+}
@@ -0,0 +1,212 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+use crate::shared::grid::Grid;
+use crate::shared::positions::Position;
+
+// Graphs are never built up front: every search takes the start node and a closure that
+// lists a node's neighbors (with the cost of stepping to each, for the weighted searches).
+
+// What a search found: the cost of the cheapest known route to every node it settled, and the
+// node each of those was reached from
+pub struct SearchResult<N> {
+    pub distances: HashMap<N, u64>,
+    pub predecessors: HashMap<N, N>,
+}
+
+impl<N: Clone + Eq + Hash> SearchResult<N> {
+    fn starting_at(start: N) -> SearchResult<N> {
+        SearchResult { distances: HashMap::from([(start, 0)]), predecessors: HashMap::new() }
+    }
+
+    pub fn distance(&self, node: &N) -> Option<u64> {
+        self.distances.get(node).copied()
+    }
+
+    // The route from the start to `node`, both ends included, or None if it was never reached
+    pub fn path_to(&self, node: &N) -> Option<Vec<N>> {
+        if !self.distances.contains_key(node) {
+            return None;
+        }
+        let mut path = vec![node.clone()];
+        while let Some(previous_node) = self.predecessors.get(path.last().unwrap()) {
+            path.push(previous_node.clone());
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+// Every step costs 1, so the distances are step counts
+pub fn bfs<N, I>(start: N, mut neighbors: impl FnMut(&N) -> I) -> SearchResult<N>
+where N: Clone + Eq + Hash, I: IntoIterator<Item = N> {
+    let mut search_result = SearchResult::starting_at(start.clone());
+    let mut bfs_queue = VecDeque::from([start]);
+    while let Some(curr_node) = bfs_queue.pop_front() {
+        let next_distance = search_result.distances[&curr_node] + 1;
+        for neighbor in neighbors(&curr_node) {
+            if !search_result.distances.contains_key(&neighbor) {
+                search_result.distances.insert(neighbor.clone(), next_distance);
+                search_result.predecessors.insert(neighbor.clone(), curr_node.clone());
+                bfs_queue.push_back(neighbor);
+            }
+        }
+    }
+    search_result
+}
+
+// Every node reachable from the start, start first, in the order a depth-first walk finds them
+pub fn dfs_flood_fill<N, I>(start: N, mut neighbors: impl FnMut(&N) -> I) -> Vec<N>
+where N: Clone + Eq + Hash, I: IntoIterator<Item = N> {
+    let mut marked_nodes: HashSet<N> = HashSet::from([start.clone()]);
+    let mut dfs_stack = vec![start];
+    let mut filled_nodes = Vec::new();
+    while let Some(curr_node) = dfs_stack.pop() {
+        for neighbor in neighbors(&curr_node) {
+            if marked_nodes.insert(neighbor.clone()) {
+                dfs_stack.push(neighbor);
+            }
+        }
+        filled_nodes.push(curr_node);
+    }
+    filled_nodes
+}
+
+// Orders the heap by cost alone, cheapest first, so nodes don't need to be Ord themselves
+struct QueueEntry<N> {
+    priority: u64,
+    node: N,
+}
+
+impl<N> PartialEq for QueueEntry<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N> Eq for QueueEntry<N> {}
+
+impl<N> PartialOrd for QueueEntry<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N> Ord for QueueEntry<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.priority.cmp(&self.priority)
+    }
+}
+
+// Settles nodes cheapest first until `goal` is settled (if given) or the queue runs dry. The
+// heuristic must never overestimate the remaining cost; returning 0 turns this into Dijkstra.
+fn best_first<N, I>(
+    start: N,
+    goal: Option<&N>,
+    mut neighbors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> u64,
+) -> SearchResult<N>
+where N: Clone + Eq + Hash, I: IntoIterator<Item = (N, u64)> {
+    let mut search_result = SearchResult::starting_at(start.clone());
+    let mut priority_queue = BinaryHeap::from([QueueEntry { priority: heuristic(&start), node: start }]);
+    let mut settled_nodes: HashSet<N> = HashSet::new();
+    while let Some(QueueEntry { node: curr_node, .. }) = priority_queue.pop() {
+        if !settled_nodes.insert(curr_node.clone()) {
+            continue;
+        }
+        if goal == Some(&curr_node) {
+            break;
+        }
+        let curr_distance = search_result.distances[&curr_node];
+        for (neighbor, step_cost) in neighbors(&curr_node) {
+            let next_distance = curr_distance + step_cost;
+            let is_shorter = match search_result.distances.get(&neighbor) {
+                Some(known_distance) => next_distance < *known_distance,
+                None => true,
+            };
+            if is_shorter {
+                search_result.distances.insert(neighbor.clone(), next_distance);
+                search_result.predecessors.insert(neighbor.clone(), curr_node.clone());
+                priority_queue.push(QueueEntry { priority: next_distance + heuristic(&neighbor), node: neighbor });
+            }
+        }
+    }
+    search_result
+}
+
+pub fn dijkstra<N, I>(start: N, neighbors: impl FnMut(&N) -> I) -> SearchResult<N>
+where N: Clone + Eq + Hash, I: IntoIterator<Item = (N, u64)> {
+    best_first(start, None, neighbors, |_| 0)
+}
+
+// Stops once the goal is settled, so only the goal's distance and path are guaranteed optimal
+pub fn astar<N, I>(
+    start: N, goal: &N, neighbors: impl FnMut(&N) -> I, heuristic: impl FnMut(&N) -> u64
+) -> SearchResult<N>
+where N: Clone + Eq + Hash, I: IntoIterator<Item = (N, u64)> {
+    best_first(start, Some(goal), neighbors, heuristic)
+}
+
+// Adapters for grids: steps go to orthogonally adjacent cells, and the closure decides which
+// cells can be entered (and what entering them costs)
+pub fn grid_steps<'a, T>(
+    grid: &'a Grid<T>, passable: impl Fn(&T) -> bool + 'a
+) -> impl Fn(&Position) -> Vec<Position> + 'a {
+    move |position| grid.orthogonal_neighbors(*position).filter(|neighbor_pos| passable(&grid[*neighbor_pos])).collect()
+}
+
+pub fn grid_costs<'a, T>(
+    grid: &'a Grid<T>, entry_cost: impl Fn(&T) -> Option<u64> + 'a
+) -> impl Fn(&Position) -> Vec<(Position, u64)> + 'a {
+    move |position| grid.orthogonal_neighbors(*position)
+        .filter_map(|neighbor_pos| Some((neighbor_pos, entry_cost(&grid[neighbor_pos])?)))
+        .collect()
+}
+
+// Admissible for grid_costs whenever every cell costs at least 1 to enter
+pub fn manhattan_heuristic(goal: Position) -> impl Fn(&Position) -> u64 {
+    move |position| (position.0.abs_diff(goal.0) + position.1.abs_diff(goal.1)) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The example risk map from 2021 day 15, whose cheapest route costs 40
+    const RISK_MAP: &str = "1163751742\n1381373672\n2136511328\n3694931569\n7463417111\n1319128137\n1359912421\n3125421639\n1293138521\n2311944581";
+
+    #[test]
+    fn bfs_counts_steps_around_walls() {
+        let maze = Grid::parse_chars("..#\n.##\n...").unwrap();
+        let search_result = bfs(Position(0, 0), grid_steps(&maze, |cell| *cell == '.'));
+        assert_eq!(search_result.distance(&Position(2, 2)), Some(4));
+        assert_eq!(search_result.distance(&Position(0, 2)), None);
+        let path = search_result.path_to(&Position(2, 2)).unwrap();
+        assert_eq!(path, vec![Position(0, 0), Position(1, 0), Position(2, 0), Position(2, 1), Position(2, 2)]);
+    }
+
+    #[test]
+    fn flood_fill_finds_the_region() {
+        let heights = Grid::parse_digits("2199\n3999\n9856").unwrap();
+        let basin = dfs_flood_fill(Position(0, 1), grid_steps(&heights, |height| *height < 9));
+        assert_eq!(basin[0], Position(0, 1));
+        assert_eq!(basin.len(), 3);
+    }
+
+    #[test]
+    fn dijkstra_and_astar_agree_on_weighted_grid() {
+        let risk_map = Grid::parse_digits(RISK_MAP).unwrap();
+        let goal = Position(9, 9);
+        let entry_cost = |risk: &u8| Some(*risk as u64);
+
+        let dijkstra_result = dijkstra(Position(0, 0), grid_costs(&risk_map, entry_cost));
+        assert_eq!(dijkstra_result.distance(&goal), Some(40));
+
+        let astar_result = astar(Position(0, 0), &goal, grid_costs(&risk_map, entry_cost), manhattan_heuristic(goal));
+        assert_eq!(astar_result.distance(&goal), Some(40));
+        let path = astar_result.path_to(&goal).unwrap();
+        let path_risk: u64 = path[1..].iter().map(|position| risk_map[*position] as u64).sum();
+        assert_eq!(path_risk, 40);
+    }
+}
@@ -0,0 +1,180 @@
+use std::collections::hash_map::{self, HashMap};
+
+use crate::shared::positions::Point2;
+
+// The smallest rectangle holding a set of points, with both corners included
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct BoundingBox {
+    pub min: Point2,
+    pub max: Point2,
+}
+
+impl BoundingBox {
+    fn around(point: Point2) -> BoundingBox {
+        BoundingBox { min: point, max: point }
+    }
+
+    fn extend(&mut self, point: Point2) {
+        self.min = Point2::new(self.min.x().min(point.x()), self.min.y().min(point.y()));
+        self.max = Point2::new(self.max.x().max(point.x()), self.max.y().max(point.y()));
+    }
+
+    fn on_edge(&self, point: Point2) -> bool {
+        point.x() == self.min.x() || point.x() == self.max.x() || point.y() == self.min.y() || point.y() == self.max.y()
+    }
+
+    pub fn contains(&self, point: Point2) -> bool {
+        (self.min.x()..=self.max.x()).contains(&point.x()) && (self.min.y()..=self.max.y()).contains(&point.y())
+    }
+
+    pub fn width(&self) -> u64 {
+        self.max.x().abs_diff(self.min.x()) + 1
+    }
+
+    pub fn height(&self) -> u64 {
+        self.max.y().abs_diff(self.min.y()) + 1
+    }
+}
+
+// A grid over the whole plane that only stores the cells that have been set. Every other cell
+// reads as the default value.
+#[derive(Clone, Debug)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point2, T>,
+    default: T,
+    bounding_box: Option<BoundingBox>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new(default: T) -> SparseGrid<T> {
+        SparseGrid { cells: HashMap::new(), default, bounding_box: None }
+    }
+
+    pub fn get(&self, point: Point2) -> &T {
+        self.cells.get(&point).unwrap_or(&self.default)
+    }
+
+    pub fn is_set(&self, point: Point2) -> bool {
+        self.cells.contains_key(&point)
+    }
+
+    pub fn set(&mut self, point: Point2, value: T) {
+        self.track(point);
+        self.cells.insert(point, value);
+    }
+
+    pub fn remove(&mut self, point: Point2) -> Option<T> {
+        let removed = self.cells.remove(&point)?;
+        // Only a cell on the edge can have been holding the box open
+        if self.bounding_box.is_some_and(|bounding_box| bounding_box.on_edge(point)) {
+            self.bounding_box = None;
+            let remaining_points: Vec<Point2> = self.cells.keys().copied().collect();
+            for remaining_point in remaining_points {
+                self.track(remaining_point);
+            }
+        }
+        Some(removed)
+    }
+
+    fn track(&mut self, point: Point2) {
+        match &mut self.bounding_box {
+            Some(bounding_box) => bounding_box.extend(point),
+            None => self.bounding_box = Some(BoundingBox::around(point)),
+        }
+    }
+
+    // Spans every set cell, or None while nothing is set
+    pub fn bounding_box(&self) -> Option<BoundingBox> {
+        self.bounding_box
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    // The set cells, in no particular order
+    pub fn iter(&self) -> hash_map::Iter<'_, Point2, T> {
+        self.cells.iter()
+    }
+
+    pub fn points(&self) -> impl Iterator<Item = &Point2> {
+        self.cells.keys()
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.values()
+    }
+
+    // Draws the bounding box one row per line, top row first, turning each cell into a character
+    pub fn render(&self, cell_char: impl Fn(&T) -> char) -> String {
+        let bounding_box = match self.bounding_box {
+            Some(bounding_box) => bounding_box,
+            None => return String::new(),
+        };
+        let mut rendered = String::new();
+        for y in bounding_box.min.y()..=bounding_box.max.y() {
+            for x in bounding_box.min.x()..=bounding_box.max.x() {
+                rendered.push(cell_char(self.get(Point2::new(x, y))));
+            }
+            rendered.push('\n');
+        }
+        rendered
+    }
+}
+
+impl<T: Clone> SparseGrid<T> {
+    // Sets the cell to the default first if it wasn't set yet
+    pub fn get_mut(&mut self, point: Point2) -> &mut T {
+        self.track(point);
+        self.cells.entry(point).or_insert_with(|| self.default.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_default_for_unset_cells() {
+        let mut grid = SparseGrid::new(0u32);
+        *grid.get_mut(Point2::new(-3, 7)) += 2;
+        *grid.get_mut(Point2::new(-3, 7)) += 1;
+        grid.set(Point2::new(1000, -1000), 5);
+        assert_eq!(*grid.get(Point2::new(-3, 7)), 3);
+        assert_eq!(*grid.get(Point2::new(0, 0)), 0);
+        assert!(!grid.is_set(Point2::new(0, 0)));
+        assert_eq!(grid.len(), 2);
+        assert_eq!(grid.values().sum::<u32>(), 8);
+    }
+
+    #[test]
+    fn tracks_bounding_box() {
+        let mut grid = SparseGrid::new(false);
+        assert_eq!(grid.bounding_box(), None);
+        grid.set(Point2::new(2, -1), true);
+        grid.set(Point2::new(-4, 3), true);
+        grid.set(Point2::new(0, 0), true);
+        let bounding_box = grid.bounding_box().unwrap();
+        assert_eq!((bounding_box.min, bounding_box.max), (Point2::new(-4, -1), Point2::new(2, 3)));
+        assert_eq!((bounding_box.width(), bounding_box.height()), (7, 5));
+
+        grid.remove(Point2::new(-4, 3));
+        assert_eq!(grid.bounding_box(), Some(BoundingBox { min: Point2::new(0, -1), max: Point2::new(2, 0) }));
+        grid.remove(Point2::new(2, -1));
+        grid.remove(Point2::new(0, 0));
+        assert_eq!(grid.bounding_box(), None);
+    }
+
+    #[test]
+    fn renders_rows_top_down() {
+        let mut grid = SparseGrid::new(false);
+        for point in [Point2::new(0, 0), Point2::new(2, 0), Point2::new(1, 1)] {
+            grid.set(point, true);
+        }
+        assert_eq!(grid.render(|is_set| if *is_set { '#' } else { '.' }), "#.#\n.#.\n");
+    }
+}
@@ -0,0 +1,105 @@
+use std::any::Any;
+use std::fmt::Display;
+
+use crate::error::Result;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(raw_input: &str) -> Result<Self::Input>;
+    fn part1(input: &Self::Input) -> Self::Answer1;
+    fn part2(input: &Self::Input) -> Self::Answer2;
+}
+
+// A Day erases the input and answer types of a Solution so that every day can sit in one
+// registry. The parsed input is kept behind `dyn Any` and handed back to the same Solution.
+pub struct Day {
+    pub number: u8,
+    parse: fn(&str) -> Result<Box<dyn Any>>,
+    part1: fn(&dyn Any) -> String,
+    part2: fn(&dyn Any) -> String,
+}
+
+pub struct ParsedInput<'a> {
+    day: &'a Day,
+    input: Box<dyn Any>,
+}
+
+fn parse_erased<S: Solution>(raw_input: &str) -> Result<Box<dyn Any>> where S::Input: 'static {
+    match S::parse(raw_input) {
+        Ok(input) => Ok(Box::new(input)),
+        Err(err) => Err(err.locate(raw_input)),
+    }
+}
+
+fn part1_erased<S: Solution>(input: &dyn Any) -> String where S::Input: 'static {
+    S::part1(downcast_input::<S>(input)).to_string()
+}
+
+fn part2_erased<S: Solution>(input: &dyn Any) -> String where S::Input: 'static {
+    S::part2(downcast_input::<S>(input)).to_string()
+}
+
+fn downcast_input<S: Solution>(input: &dyn Any) -> &S::Input where S::Input: 'static {
+    match input.downcast_ref::<S::Input>() {
+        Some(typed_input) => typed_input,
+        None => panic!("day {} was handed an input parsed by another day", S::DAY),
+    }
+}
+
+impl Day {
+    pub const fn new<S: Solution>() -> Day where S::Input: 'static {
+        Day {
+            number: S::DAY,
+            parse: parse_erased::<S>,
+            part1: part1_erased::<S>,
+            part2: part2_erased::<S>,
+        }
+    }
+
+    pub fn parse(&self, raw_input: &str) -> Result<ParsedInput<'_>> {
+        Ok(ParsedInput { day: self, input: (self.parse)(raw_input)? })
+    }
+
+    pub fn solve(&self, raw_input: &str, part: Part) -> Result<String> {
+        Ok(self.parse(raw_input)?.solve(part))
+    }
+}
+
+impl ParsedInput<'_> {
+    pub fn part1(&self) -> String {
+        (self.day.part1)(self.input.as_ref())
+    }
+
+    pub fn part2(&self) -> String {
+        (self.day.part2)(self.input.as_ref())
+    }
+
+    pub fn solve(&self, part: Part) -> String {
+        match part {
+            Part::One => self.part1(),
+            Part::Two => self.part2(),
+        }
+    }
+}